@@ -0,0 +1,242 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC methods querying the deployment permissions pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{traits::ToRpcParams, RpcResult},
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned, INTERNAL_ERROR_CODE},
+    Methods, MethodsError, RpcModule,
+};
+use pallet_deployment_permissions::{
+    runner::{CALL_REJECTED, DEPLOYMENT_REJECTED, REJECTED_TRANSACTION_CODE},
+    DeployQuota, DeployRequest, DeployerInfo, DeploymentMode,
+};
+use pallet_deployment_permissions_runtime_api::{
+    DeploymentPermissionsApi as DeploymentPermissionsRuntimeApi, MAX_DEPLOYERS_PER_PAGE,
+};
+use serde_json::value::RawValue;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H160;
+use vflow_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash};
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Error code returned by the Ethereum RPC methods when the deployment permissions reject the
+/// transaction.
+pub const TRANSACTION_REJECTED: i32 = -32010;
+
+/// Ethereum RPC methods running or submitting a transaction the deployment permissions can
+/// reject.
+const CHECKED_ETH_METHODS: [&str; 3] = ["eth_call", "eth_estimateGas", "eth_sendRawTransaction"];
+
+/// Deployment permissions RPC methods.
+#[rpc(server, namespace = "vflow")]
+pub trait DeploymentPermissionsApi<BlockHash> {
+    /// Returns `true` if `address` is allowed to deploy contracts.
+    #[method(name = "isDeployer")]
+    fn is_deployer(&self, address: H160, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Returns at most `limit` deployers, starting right after `start_after`, if any. The limit
+    /// is capped to [`MAX_DEPLOYERS_PER_PAGE`].
+    #[method(name = "deployers")]
+    fn deployers(
+        &self,
+        start_after: Option<H160>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(H160, DeployerInfo<BlockNumber>)>>;
+
+    /// Returns the policy currently applied to contract deployments.
+    #[method(name = "deploymentMode")]
+    fn deployment_mode(&self, at: Option<BlockHash>) -> RpcResult<DeploymentMode>;
+
+    /// Returns the deploy quota of `address`, if any.
+    #[method(name = "deployQuota")]
+    fn deploy_quota(
+        &self,
+        address: H160,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DeployQuota<BlockNumber>>>;
+
+    /// Returns the deploy permission requests waiting for a decision, keyed by requesting
+    /// address.
+    #[method(name = "pendingRequests")]
+    fn pending_requests(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(H160, DeployRequest<AccountId, Balance, BlockNumber>)>>;
+
+    /// Returns the total deployment bonds held from `deployer` for the contracts it deployed
+    /// which have not been retired yet.
+    #[method(name = "deploymentBonds")]
+    fn deployment_bonds(&self, deployer: H160, at: Option<BlockHash>) -> RpcResult<Balance>;
+}
+
+/// Implements [`DeploymentPermissionsApiServer`] on top of the runtime API.
+pub struct DeploymentPermissions<C> {
+    client: Arc<C>,
+}
+
+impl<C> DeploymentPermissions<C> {
+    /// Creates a new instance of the deployment permissions RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> DeploymentPermissions<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn at(&self, at: Option<Hash>) -> Hash {
+        at.unwrap_or_else(|| self.client.info().best_hash)
+    }
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query deployment permissions.",
+        Some(format!("{err:?}")),
+    )
+}
+
+impl<C> DeploymentPermissionsApiServer<Hash> for DeploymentPermissions<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DeploymentPermissionsRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    fn is_deployer(&self, address: H160, at: Option<Hash>) -> RpcResult<bool> {
+        self.client
+            .runtime_api()
+            .is_deployer(self.at(at), address)
+            .map_err(runtime_error)
+    }
+
+    fn deployers(
+        &self,
+        start_after: Option<H160>,
+        limit: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(H160, DeployerInfo<BlockNumber>)>> {
+        self.client
+            .runtime_api()
+            .deployers(self.at(at), start_after, limit.min(MAX_DEPLOYERS_PER_PAGE))
+            .map_err(runtime_error)
+    }
+
+    fn deployment_mode(&self, at: Option<Hash>) -> RpcResult<DeploymentMode> {
+        self.client
+            .runtime_api()
+            .deployment_mode(self.at(at))
+            .map_err(runtime_error)
+    }
+
+    fn deploy_quota(
+        &self,
+        address: H160,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<DeployQuota<BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .deploy_quota(self.at(at), address)
+            .map_err(runtime_error)
+    }
+
+    fn pending_requests(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(H160, DeployRequest<AccountId, Balance, BlockNumber>)>> {
+        self.client
+            .runtime_api()
+            .pending_requests(self.at(at))
+            .map_err(runtime_error)
+    }
+
+    fn deployment_bonds(&self, deployer: H160, at: Option<Hash>) -> RpcResult<Balance> {
+        self.client
+            .runtime_api()
+            .deployment_bonds(self.at(at), deployer)
+            .map_err(runtime_error)
+    }
+}
+
+/// Parameters of a method call, forwarded as they have been received.
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Returns the methods of `eth` with the [`CHECKED_ETH_METHODS`] reporting the transactions
+/// rejected by the deployment permissions with the [`TRANSACTION_REJECTED`] code. Frontier
+/// reports them as internal errors, like any other failure of the runtime.
+pub fn with_rejection_errors(
+    eth: impl Into<Methods>,
+) -> Result<RpcModule<()>, Box<dyn std::error::Error + Send + Sync>> {
+    let eth: Methods = eth.into();
+    let mut others = eth.clone();
+    let mut module = RpcModule::new(());
+    for method in CHECKED_ETH_METHODS {
+        others.remove(method);
+        let eth = eth.clone();
+        module.register_async_method(method, move |params, _, _| {
+            let eth = eth.clone();
+            async move {
+                let params = params
+                    .as_str()
+                    .map(|params| RawValue::from_string(params.to_owned()))
+                    .transpose()
+                    .map_err(|err| rejection_error(MethodsError::Parse(err)))?;
+                eth.call::<_, serde_json::Value>(method, RawParams(params))
+                    .await
+                    .map_err(rejection_error)
+            }
+        })?;
+    }
+    module.merge(others)?;
+    Ok(module)
+}
+
+/// Reports `err` with the [`TRANSACTION_REJECTED`] code if the deployment permissions rejected the
+/// transaction: either its execution exited with a rejection reason, or the transaction pool
+/// refused it with the dedicated validity error.
+fn rejection_error(err: MethodsError) -> ErrorObjectOwned {
+    let err = match err {
+        MethodsError::JsonRpc(err) => err,
+        err => return ErrorObject::owned(INTERNAL_ERROR_CODE, err.to_string(), None::<()>),
+    };
+    let message = err.message();
+    let rejected = message.contains(DEPLOYMENT_REJECTED)
+        || message.contains(CALL_REJECTED)
+        || message.contains(&format!("Custom({REJECTED_TRANSACTION_CODE})"));
+    if rejected {
+        ErrorObject::owned(
+            TRANSACTION_REJECTED,
+            "Transaction rejected by the deployment permissions.",
+            Some(message),
+        )
+    } else {
+        err
+    }
+}
@@ -40,6 +40,8 @@ use sp_core::H256;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
 
+use crate::rpc::deployment_permissions::with_rejection_errors;
+
 /// Extra dependencies for Ethereum compatibility.
 pub struct EthDeps<B: BlockT, C, P, CT, CIDP> {
     /// The client instance to use.
@@ -141,7 +143,7 @@ where
         signers.push(Box::new(EthDevSigner::new()) as Box<dyn EthSigner>);
     }
 
-    io.merge(
+    io.merge(with_rejection_errors(
         Eth::<B, C, P, CT, BE, CIDP, EC>::new(
             client.clone(),
             pool.clone(),
@@ -162,7 +164,7 @@ where
         )
         .replace_config::<EC>()
         .into_rpc(),
-    )?;
+    )?)?;
 
     if let Some(filter_pool) = filter_pool {
         io.merge(
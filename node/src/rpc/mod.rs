@@ -20,6 +20,7 @@
 //! capabilities that are specific to this project's runtime configuration.
 
 #![warn(missing_docs)]
+mod deployment_permissions;
 mod eth;
 use std::sync::Arc;
 
@@ -38,7 +39,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::H256;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
-use vflow_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use vflow_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 pub use self::eth::EthDeps;
 use crate::rpc::eth::create_eth;
@@ -98,11 +99,18 @@ where
     C::Api: BlockBuilder<Block>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
+    C::Api: pallet_deployment_permissions_runtime_api::DeploymentPermissionsApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+    >,
     P: TransactionPool<Block = Block, Hash = H256> + Sync + Send + 'static,
     CIDP: CreateInherentDataProviders<Block, ()> + Send + 'static,
     CT: fp_rpc::ConvertTransaction<<Block as BlockT>::Extrinsic> + Send + Sync + 'static,
     BE: Backend<Block> + 'static,
 {
+    use deployment_permissions::{DeploymentPermissions, DeploymentPermissionsApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -115,7 +123,8 @@ where
     } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(DeploymentPermissions::new(client).into_rpc())?;
 
     let mut module = create_eth::<_, _, _, _, _, _, DefaultEthConfig<C, BE>>(
         module,
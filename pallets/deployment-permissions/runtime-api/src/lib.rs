@@ -0,0 +1,61 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the deployment permissions pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pallet_deployment_permissions::{DeployQuota, DeployRequest, DeployerInfo, DeploymentMode};
+use parity_scale_codec::Codec;
+use sp_core::H160;
+
+/// The maximum number of deployers returned by a single `deployers` call.
+pub const MAX_DEPLOYERS_PER_PAGE: u32 = 1_000;
+
+sp_api::decl_runtime_apis! {
+    /// Queries the state of `pallet-deployment-permissions`.
+    pub trait DeploymentPermissionsApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns `true` if `address` has a deploy permission which has not expired yet.
+        fn is_deployer(address: H160) -> bool;
+
+        /// Returns at most `limit` addresses having a deploy permission which has not expired
+        /// yet, starting right after `start_after`, if any. The last returned address can be used
+        /// as `start_after` to fetch the next page. The limit is capped to
+        /// [`MAX_DEPLOYERS_PER_PAGE`].
+        fn deployers(start_after: Option<H160>, limit: u32) -> Vec<(H160, DeployerInfo<BlockNumber>)>;
+
+        /// Returns the policy currently applied to contract deployments.
+        fn deployment_mode() -> DeploymentMode;
+
+        /// Returns the deploy quota of `address`, if any.
+        fn deploy_quota(address: H160) -> Option<DeployQuota<BlockNumber>>;
+
+        /// Returns the deploy permission requests waiting for a decision, keyed by requesting
+        /// address.
+        fn pending_requests() -> Vec<(H160, DeployRequest<AccountId, Balance, BlockNumber>)>;
+
+        /// Returns the total deployment bonds held from `deployer` for the contracts it deployed
+        /// which have not been retired yet.
+        fn deployment_bonds(deployer: H160) -> Balance;
+    }
+}
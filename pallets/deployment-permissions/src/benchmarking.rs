@@ -21,14 +21,30 @@ mod benchmarks {
     use super::*;
     #[cfg(test)]
     use crate::pallet::Pallet as Template;
-    use frame_system::RawOrigin;
+    use frame_support::{
+        sp_runtime::traits::{Convert, Saturating},
+        traits::{
+            fungible::{Inspect, Mutate},
+            EnsureOrigin, Get,
+        },
+    };
+    use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+    /// Registers a deployer manager and returns its signed origin: going through the
+    /// `Managers` lookup is the most expensive way to pass the manager check.
+    fn manager_origin<T: Config>() -> RawOrigin<T::AccountId> {
+        let caller: T::AccountId = whitelisted_caller();
+        Managers::<T>::insert(T::AccountIdToAddress::convert(caller.clone()), ());
+        RawOrigin::Signed(caller)
+    }
 
     #[benchmark]
     fn grant_deploy_permission() {
         let address = H160::repeat_byte(42);
+        let origin = manager_origin::<T>();
 
         #[extrinsic_call]
-        grant_deploy_permission(RawOrigin::Root, address);
+        grant_deploy_permission(origin, address);
 
         assert!(Deployers::<T>::get(address).is_some());
     }
@@ -36,13 +52,488 @@ mod benchmarks {
     #[benchmark]
     fn revoke_deploy_permission() {
         let address = H160::repeat_byte(42);
-        Deployers::<T>::insert(address, ());
+        Deployers::<T>::insert(address, DeployerInfo::default());
+        let origin = manager_origin::<T>();
 
         #[extrinsic_call]
-        revoke_deploy_permission(RawOrigin::Root, address);
+        revoke_deploy_permission(origin, address);
 
         assert!(Deployers::<T>::get(address).is_none());
     }
 
+    #[benchmark]
+    fn grant_temporary_deploy_permission() {
+        let address = H160::repeat_byte(42);
+        let expires_at: BlockNumberFor<T> = 100u32.into();
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        grant_temporary_deploy_permission(origin, address, expires_at);
+
+        assert_eq!(
+            Deployers::<T>::get(address).and_then(|info| info.expires_at),
+            Some(expires_at)
+        );
+    }
+
+    #[benchmark]
+    fn prune_expired_deployers(n: Linear<0, { T::MaxPrunedPerBlock::get() }>) {
+        // Worst case: every inspected deployer has expired and must be removed.
+        let expires_at: BlockNumberFor<T> = 1u32.into();
+        (0..n).for_each(|i| {
+            Deployers::<T>::insert(
+                H160::from_low_u64_be(i as u64),
+                DeployerInfo {
+                    expires_at: Some(expires_at),
+                    ..Default::default()
+                },
+            );
+        });
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+
+        #[block]
+        {
+            Pallet::<T>::prune_expired_deployers(n);
+        }
+
+        assert_eq!(Deployers::<T>::iter_keys().count(), 0);
+    }
+
+    #[benchmark]
+    fn allow_init_code_hash() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let code_hash = H256::repeat_byte(42);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, code_hash);
+
+        assert!(AllowedInitCodeHashes::<T>::contains_key(code_hash));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn disallow_init_code_hash() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let code_hash = H256::repeat_byte(42);
+        AllowedInitCodeHashes::<T>::insert(code_hash, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, code_hash);
+
+        assert!(!AllowedInitCodeHashes::<T>::contains_key(code_hash));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_factory() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(ApprovedFactories::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_factory() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+        ApprovedFactories::<T>::insert(address, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(!ApprovedFactories::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_manager() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(Managers::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_manager() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+        Managers::<T>::insert(address, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(!Managers::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_deploy_quota() {
+        let address = H160::repeat_byte(42);
+        let quota = DeployQuota {
+            max_total: Some(10),
+            max_per_window: Some(2),
+            window: 100u32.into(),
+        };
+        DeployCounters::<T>::insert(
+            address,
+            DeployCounter {
+                total: 1,
+                recent: BoundedVec::truncate_from(alloc::vec![1u32.into()]),
+            },
+        );
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        set_deploy_quota(origin, address, quota.clone());
+
+        assert_eq!(DeployQuotas::<T>::get(address), Some(quota));
+        assert!(!DeployCounters::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn remove_deploy_quota() {
+        let address = H160::repeat_byte(42);
+        DeployQuotas::<T>::insert(
+            address,
+            DeployQuota {
+                max_total: Some(10),
+                max_per_window: Some(2),
+                window: 100u32.into(),
+            },
+        );
+        DeployCounters::<T>::insert(
+            address,
+            DeployCounter {
+                total: 1,
+                recent: BoundedVec::truncate_from(alloc::vec![1u32.into()]),
+            },
+        );
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        remove_deploy_quota(origin, address);
+
+        assert!(!DeployQuotas::<T>::contains_key(address));
+        assert!(!DeployCounters::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn set_deployment_mode() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, DeploymentMode::Frozen);
+
+        assert_eq!(Mode::<T>::get(), DeploymentMode::Frozen);
+
+        Ok(())
+    }
+
+    /// Funds a requester and submits its deploy permission request.
+    fn pending_request<T: Config>() -> H160 {
+        let requester: T::AccountId = account("requester", 0, 0);
+        let deposit = T::RequestDeposit::get();
+        T::Currency::set_balance(
+            &requester,
+            T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(2u32.into())),
+        );
+        let address = T::AccountIdToAddress::convert(requester.clone());
+        Pallet::<T>::request_deploy_permission(
+            RawOrigin::Signed(requester).into(),
+            Some(H256::repeat_byte(1)),
+        )
+        .expect("request is valid");
+        address
+    }
+
+    #[benchmark]
+    fn request_deploy_permission() {
+        let caller: T::AccountId = whitelisted_caller();
+        let deposit = T::RequestDeposit::get();
+        T::Currency::set_balance(
+            &caller,
+            T::Currency::minimum_balance().saturating_add(deposit.saturating_mul(2u32.into())),
+        );
+        let address = T::AccountIdToAddress::convert(caller.clone());
+
+        #[extrinsic_call]
+        request_deploy_permission(RawOrigin::Signed(caller), Some(H256::repeat_byte(1)));
+
+        assert!(PendingRequests::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn approve_deploy_permission_request() {
+        let address = pending_request::<T>();
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        approve_deploy_permission_request(origin, address);
+
+        assert!(!PendingRequests::<T>::contains_key(address));
+        assert!(Deployers::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn reject_deploy_permission_request() {
+        let address = pending_request::<T>();
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        reject_deploy_permission_request(origin, address);
+
+        assert!(!PendingRequests::<T>::contains_key(address));
+        assert!(!Deployers::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn set_bytecode_policy() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let policy = BytecodePolicy {
+            max_init_code_len: Some(u32::MAX),
+            max_runtime_code_len: Some(u32::MAX),
+            disallowed_opcodes: (0..=u8::MAX)
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("all opcodes fit"),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, policy.clone());
+
+        assert_eq!(CodePolicy::<T>::get(), policy);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn pause_contract() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(PausedContracts::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unpause_contract() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let address = H160::repeat_byte(42);
+        PausedContracts::<T>::insert(address, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, address);
+
+        assert!(!PausedContracts::<T>::contains_key(address));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_deployer_metadata() {
+        let address = H160::repeat_byte(42);
+        Deployers::<T>::insert(address, DeployerInfo::default());
+        let label: DeployerLabel = alloc::vec![b'x'; MaxLabelLen::get() as usize]
+            .try_into()
+            .expect("label fits");
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        set_deployer_metadata(origin, address, label.clone(), Some(H256::repeat_byte(1)));
+
+        assert_eq!(
+            Deployers::<T>::get(address).map(|info| info.label),
+            Some(label)
+        );
+    }
+
+    /// Returns `n` distinct addresses for batch calls.
+    fn batch<T: Config>(n: u32) -> BoundedVec<H160, T::MaxBatchSize> {
+        (0..n)
+            .map(|i| H160::from_low_u64_be(i as u64 + 1))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("n is within MaxBatchSize")
+    }
+
+    #[benchmark]
+    fn grant_deploy_permissions(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let addresses = batch::<T>(n);
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        grant_deploy_permissions(origin, addresses.clone(), BatchMode::AllOrNothing);
+
+        assert!(addresses
+            .iter()
+            .all(|address| Deployers::<T>::contains_key(address)));
+    }
+
+    #[benchmark]
+    fn revoke_deploy_permissions(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let addresses = batch::<T>(n);
+        addresses
+            .iter()
+            .for_each(|address| Deployers::<T>::insert(address, DeployerInfo::default()));
+        let origin = manager_origin::<T>();
+
+        #[extrinsic_call]
+        revoke_deploy_permissions(origin, addresses.clone(), BatchMode::AllOrNothing);
+
+        assert_eq!(Deployers::<T>::iter_keys().count(), 0);
+    }
+
+    /// Holds the deployment bond of a contract from a funded deployer.
+    fn bonded_contract<T: Config>() -> H160 {
+        let deployer: T::AccountId = account("deployer", 0, 0);
+        let bond = T::DeploymentBond::get();
+        T::Currency::set_balance(
+            &deployer,
+            T::Currency::minimum_balance().saturating_add(bond.saturating_mul(2u32.into())),
+        );
+        T::Currency::hold(&HoldReason::DeploymentBond.into(), &deployer, bond)
+            .expect("deployer is funded");
+        let contract = H160::repeat_byte(42);
+        ContractBonds::<T>::insert(
+            contract,
+            ContractBond {
+                deployer: T::AccountIdToAddress::convert(deployer),
+                amount: bond,
+            },
+        );
+        contract
+    }
+
+    #[benchmark]
+    fn retire_contract() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let contract = bonded_contract::<T>();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, contract);
+
+        assert!(!ContractBonds::<T>::contains_key(contract));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn release_deployment_bond() {
+        let caller: T::AccountId = whitelisted_caller();
+        let contract = bonded_contract::<T>();
+
+        #[extrinsic_call]
+        release_deployment_bond(RawOrigin::Signed(caller), contract);
+
+        assert!(!ContractBonds::<T>::contains_key(contract));
+    }
+
+    /// Runs the checks and the bookkeeping of a top-level create on their most expensive path:
+    /// the expired permission of the deployer is looked up before falling back on an allowed
+    /// init code, a deploy quota is enforced, the deployment bond is held and the largest
+    /// runtime code the EVM can deploy is scanned for disallowed opcodes.
+    #[benchmark]
+    fn check_create() {
+        let caller: T::AccountId = whitelisted_caller();
+        let deployer = T::AccountIdToAddress::convert(caller.clone());
+        let bond = T::DeploymentBond::get();
+        T::Currency::set_balance(
+            &caller,
+            T::Currency::minimum_balance().saturating_add(bond.saturating_mul(2u32.into())),
+        );
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+        Mode::<T>::put(DeploymentMode::Permissioned);
+        Deployers::<T>::insert(
+            deployer,
+            DeployerInfo {
+                expires_at: Some(1u32.into()),
+                ..Default::default()
+            },
+        );
+        let init = alloc::vec![0u8; 1024];
+        let init_code_hash = H256(sp_io::hashing::keccak_256(&init));
+        AllowedInitCodeHashes::<T>::insert(init_code_hash, ());
+        CodePolicy::<T>::put(BytecodePolicy {
+            max_init_code_len: Some(u32::MAX),
+            max_runtime_code_len: Some(u32::MAX),
+            // Every opcode but the `STOP` the runtime code is made of.
+            disallowed_opcodes: (1..=u8::MAX)
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("all opcodes fit"),
+        });
+        let max_per_window = MaxDeploysPerWindow::get();
+        DeployQuotas::<T>::insert(
+            deployer,
+            DeployQuota {
+                max_total: Some(u32::MAX),
+                max_per_window: Some(max_per_window),
+                window: 100u32.into(),
+            },
+        );
+        DeployCounters::<T>::insert(
+            deployer,
+            DeployCounter {
+                total: max_per_window - 1,
+                recent: BoundedVec::truncate_from(alloc::vec![
+                    1u32.into();
+                    max_per_window as usize - 1
+                ]),
+            },
+        );
+        // The code size limit of EIP-170.
+        let code = alloc::vec![0u8; 24 * 1024];
+        let contract = H160::repeat_byte(42);
+
+        #[block]
+        {
+            Pallet::<T>::check_create(&deployer, &init).expect("create is allowed");
+            Pallet::<T>::check_runtime_code(|| code).expect("code is allowed");
+            Pallet::<T>::note_create(&deployer, &contract, &init_code_hash)
+                .expect("deployment is recorded");
+        }
+
+        assert!(DeployedContracts::<T>::contains_key(contract));
+    }
+
+    #[benchmark]
+    fn check_call() {
+        let target = H160::repeat_byte(42);
+
+        #[block]
+        {
+            Pallet::<T>::check_call(&target).expect("target is not paused");
+        }
+    }
+
     impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -20,6 +20,7 @@ extern crate alloc;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 pub mod runner;
@@ -29,21 +30,364 @@ pub mod weights;
 
 pub use crate::weights::WeightInfo;
 use alloc::vec::Vec;
-use frame_support::sp_runtime::DispatchError;
+use core::marker::PhantomData;
+use frame_support::{
+    pallet_prelude::{ConstU32, RuntimeDebug},
+    sp_runtime::{
+        traits::{Convert, Saturating, Zero},
+        DispatchError,
+    },
+    traits::{fungible::MutateHold, Contains, Get},
+    weights::Weight,
+    BoundedVec,
+};
 pub use pallet::*;
-use sp_core::H160;
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256};
+
+/// Global policy applied to contract deployments.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum DeploymentMode {
+    /// Only deployers, allowed init codes and approved factories can deploy.
+    #[default]
+    Permissioned,
+    /// Anyone can deploy.
+    Open,
+    /// Nobody can deploy, deployers included.
+    Frozen,
+}
+
+/// How a batch call handles the items which cannot be processed.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum BatchMode {
+    /// The whole call fails if any item fails.
+    #[default]
+    AllOrNothing,
+    /// Failing items are skipped and reported with an event.
+    SkipFailed,
+}
+
+/// Who granted a deploy permission.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum Granter {
+    /// The permission has been granted by `Config::ManagerOrigin`.
+    #[default]
+    Admin,
+    /// The permission has been granted by a deployer manager.
+    Manager(H160),
+}
+
+/// The maximum length in bytes of a [`DeployerLabel`].
+pub type MaxLabelLen = ConstU32<32>;
+
+/// A short human readable label identifying a deployer.
+pub type DeployerLabel = BoundedVec<u8, MaxLabelLen>;
+
+/// Information stored for every address having deploy permission.
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub struct DeployerInfo<BlockNumber> {
+    /// The block from which the deploy permission is no longer valid, if any.
+    pub expires_at: Option<BlockNumber>,
+    /// Who granted the permission.
+    pub granted_by: Granter,
+    /// The block in which the permission has been granted.
+    pub granted_at: BlockNumber,
+    /// A short label identifying the deployer.
+    pub label: DeployerLabel,
+    /// The hash of off-chain notes about the deployer, if any.
+    pub notes_hash: Option<H256>,
+}
+
+impl<BlockNumber: PartialOrd> DeployerInfo<BlockNumber> {
+    /// Returns `true` if the permission is no longer valid at block `now`.
+    pub fn is_expired(&self, now: &BlockNumber) -> bool {
+        self.expires_at
+            .as_ref()
+            .is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// The maximum number of deployments a [`DeployQuota`] can allow within a window.
+pub type MaxDeploysPerWindow = ConstU32<100>;
+
+/// Limits on the number of contracts a deployer can create.
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub struct DeployQuota<BlockNumber> {
+    /// The maximum number of deployments since the quota has been set, if any.
+    pub max_total: Option<u32>,
+    /// The maximum number of deployments within any `window` consecutive blocks, if any. At most
+    /// [`MaxDeploysPerWindow`].
+    pub max_per_window: Option<u32>,
+    /// The length in blocks of the window `max_per_window` applies to.
+    pub window: BlockNumber,
+}
+
+/// Deployments performed by an address having a [`DeployQuota`].
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DeployCounter<BlockNumber> {
+    /// The number of deployments since the quota has been set.
+    pub total: u32,
+    /// The blocks of the latest deployments, oldest first.
+    pub recent: BoundedVec<BlockNumber, MaxDeploysPerWindow>,
+}
+
+impl<BlockNumber: Copy + PartialOrd + Saturating> DeployCounter<BlockNumber> {
+    /// Returns the number of deployments within the `window` blocks ending at `now`.
+    pub fn in_window_at(&self, window: BlockNumber, now: BlockNumber) -> u32 {
+        self.recent
+            .iter()
+            .filter(|block| block.saturating_add(window) > now)
+            .count() as u32
+    }
+
+    /// Records a deployment at block `now`, forgetting those which are no longer within the
+    /// `window` blocks ending at `now`.
+    pub fn record(&mut self, window: BlockNumber, now: BlockNumber) {
+        self.recent
+            .retain(|block| block.saturating_add(window) > now);
+        // Only reachable without a window limit: the oldest deployments no longer matter.
+        if self.recent.is_full() {
+            self.recent.remove(0);
+        }
+        let _ = self.recent.try_push(now);
+        self.total.saturating_inc();
+    }
+}
+
+/// Provenance of a contract deployed through the permissioned runner.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DeployedContract<BlockNumber> {
+    /// The address which deployed the contract.
+    pub deployer: H160,
+    /// The keccak256 hash of the init code.
+    pub init_code_hash: H256,
+    /// The block in which the contract has been deployed.
+    pub deployed_at: BlockNumber,
+}
+
+/// The maximum number of opcodes a [`BytecodePolicy`] can disallow.
+pub type MaxDisallowedOpcodes = ConstU32<256>;
+
+/// Constraints on the bytecode of contracts deployed through the permissioned runner.
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct BytecodePolicy {
+    /// The maximum length in bytes of the init code, if any.
+    pub max_init_code_len: Option<u32>,
+    /// The maximum length in bytes of the deployed runtime code, if any.
+    pub max_runtime_code_len: Option<u32>,
+    /// Opcodes which must not appear in the deployed runtime code.
+    pub disallowed_opcodes: BoundedVec<u8, MaxDisallowedOpcodes>,
+}
+
+impl BytecodePolicy {
+    /// Returns `true` if the policy puts constraints on the deployed runtime code.
+    pub fn constrains_runtime_code(&self) -> bool {
+        self.max_runtime_code_len.is_some() || !self.disallowed_opcodes.is_empty()
+    }
+
+    /// Returns the first disallowed opcode found in `code`, if any. The immediate data of
+    /// `PUSH1`..`PUSH32` is skipped, since it is never executed.
+    pub fn first_disallowed_opcode(&self, code: &[u8]) -> Option<u8> {
+        const PUSH1: u8 = 0x60;
+        const PUSH32: u8 = 0x7f;
+
+        if self.disallowed_opcodes.is_empty() {
+            return None;
+        }
+        let mut pc = 0;
+        while let Some(&opcode) = code.get(pc) {
+            if self.disallowed_opcodes.contains(&opcode) {
+                return Some(opcode);
+            }
+            pc += match opcode {
+                PUSH1..=PUSH32 => (opcode - PUSH1) as usize + 2,
+                _ => 1,
+            };
+        }
+        None
+    }
+}
+
+/// A pending request for deploy permission.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub struct DeployRequest<AccountId, Balance, BlockNumber> {
+    /// The account which submitted the request and holds the deposit.
+    pub who: AccountId,
+    /// The deposit held until the request is approved or rejected.
+    pub deposit: Balance,
+    /// The hash of off-chain metadata describing the applicant, if any.
+    pub metadata: Option<H256>,
+    /// The block in which the request has been submitted.
+    pub requested_at: BlockNumber,
+}
+
+/// A deposit held from the deployer of a contract until the contract is retired or its code is
+/// removed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ContractBond<Balance> {
+    /// The address which deployed the contract and from which the bond is held.
+    pub deployer: H160,
+    /// The amount held.
+    pub amount: Balance,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{dispatch::DispatchResult, pallet_prelude::*, DefaultNoBound};
+    use frame_support::{
+        dispatch::DispatchResult,
+        pallet_prelude::*,
+        sp_runtime::traits::{Convert, Zero},
+        traits::{
+            fungible::{self, Inspect, InspectHold, MutateHold},
+            tokens::{Fortitude, Precision},
+        },
+        DefaultNoBound,
+    };
     use frame_system::pallet_prelude::*;
 
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type DeployRequestOf<T> =
+        DeployRequest<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+    /// Reasons for the pallet to hold funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds held while a deploy permission request is pending.
+        DeployPermissionRequest,
+        /// Funds held for every contract deployed, until it is retired or its code is removed.
+        DeploymentBond,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// Origin allowed to administer deploy permissions and deployer managers.
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Converts the account of a signed origin to the address checked against `Managers`.
+        type AccountIdToAddress: Convert<Self::AccountId, H160>;
+        /// Converts the address of a deployer to the account from which its bonds are held.
+        type AddressToAccountId: Convert<H160, Self::AccountId>;
+        /// The maximum number of deployers inspected for expiry in a single `on_idle` call.
+        #[pallet::constant]
+        type MaxPrunedPerBlock: Get<u32>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// The currency used for deploy permission request deposits.
+        type Currency: fungible::Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The deposit held from the account requesting deploy permission.
+        #[pallet::constant]
+        type RequestDeposit: Get<BalanceOf<Self>>;
+        /// The maximum number of deploy permission requests waiting for a decision.
+        #[pallet::constant]
+        type MaxPendingRequests: Get<u32>;
+        /// The maximum number of addresses accepted by a single batch call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// The deposit held from the deployer for every contract successfully deployed.
+        #[pallet::constant]
+        type DeploymentBond: Get<BalanceOf<Self>>;
+        /// Tells whether an address still has code: the bond of a contract whose code has been
+        /// removed can be released by anyone.
+        type HasCode: Contains<H160>;
         type WeightInfo: WeightInfo;
     }
 
@@ -54,12 +398,152 @@ pub mod pallet {
         DeployPermissionGranted {
             /// The address to which deploy permission has been granted.
             address: H160,
+            /// The block from which the permission is no longer valid, if any.
+            expires_at: Option<BlockNumberFor<T>>,
+            /// Who granted the permission.
+            granted_by: Granter,
         },
         /// Deploy permission has been revoked from an address.
         DeployPermissionRevoked {
             /// The address from which deploy permission has been revoked.
             address: H160,
         },
+        /// An item of a batch call has failed and has been skipped.
+        BatchItemSkipped {
+            /// The address which has not been processed.
+            address: H160,
+            /// The error which made the item fail.
+            error: DispatchError,
+        },
+        /// Deploy permission of an address has expired and has been removed.
+        DeployPermissionExpired {
+            /// The address whose deploy permission has expired.
+            address: H160,
+        },
+        /// An init code hash has been allowed to be deployed by anyone.
+        InitCodeHashAllowed {
+            /// The keccak256 hash of the allowed init code.
+            code_hash: H256,
+        },
+        /// An init code hash is no longer allowed to be deployed by anyone.
+        InitCodeHashDisallowed {
+            /// The keccak256 hash of the disallowed init code.
+            code_hash: H256,
+        },
+        /// A contract has been approved as factory.
+        FactoryApproved {
+            /// The address of the approved factory contract.
+            address: H160,
+        },
+        /// A contract is no longer approved as factory.
+        FactoryRevoked {
+            /// The address of the revoked factory contract.
+            address: H160,
+        },
+        /// An address has been made deployer manager.
+        ManagerAdded {
+            /// The address of the new manager.
+            address: H160,
+        },
+        /// An address is no longer deployer manager.
+        ManagerRemoved {
+            /// The address of the removed manager.
+            address: H160,
+        },
+        /// A deploy quota has been set for an address.
+        DeployQuotaSet {
+            /// The address the quota applies to.
+            address: H160,
+            /// The new quota.
+            quota: DeployQuota<BlockNumberFor<T>>,
+        },
+        /// The deploy quota of an address has been removed.
+        DeployQuotaRemoved {
+            /// The address which is no longer limited.
+            address: H160,
+        },
+        /// The deployment mode has been changed.
+        ModeChanged {
+            /// The new deployment mode.
+            mode: DeploymentMode,
+        },
+        /// A contract has been deployed through the permissioned runner.
+        ContractDeployed {
+            /// The address of the deployed contract.
+            contract: H160,
+            /// The address which deployed the contract.
+            deployer: H160,
+            /// The keccak256 hash of the init code.
+            init_code_hash: H256,
+        },
+        /// An address has requested deploy permission.
+        DeployPermissionRequested {
+            /// The address which requested deploy permission.
+            address: H160,
+            /// The hash of off-chain metadata describing the applicant, if any.
+            metadata: Option<H256>,
+            /// The deposit held until the request is decided.
+            deposit: BalanceOf<T>,
+        },
+        /// A deploy permission request has been approved and its deposit released.
+        DeployPermissionRequestApproved {
+            /// The address whose request has been approved.
+            address: H160,
+        },
+        /// A deploy permission request has been rejected and its deposit slashed.
+        DeployPermissionRequestRejected {
+            /// The address whose request has been rejected.
+            address: H160,
+            /// The amount slashed from the deposit.
+            slashed: BalanceOf<T>,
+        },
+        /// The label and notes of a deployer have been changed.
+        DeployerMetadataSet {
+            /// The address of the deployer.
+            address: H160,
+            /// The new label.
+            label: DeployerLabel,
+            /// The hash of the new off-chain notes, if any.
+            notes_hash: Option<H256>,
+        },
+        /// The bytecode policy has been changed.
+        BytecodePolicySet {
+            /// The new bytecode policy.
+            policy: BytecodePolicy,
+        },
+        /// Top-level calls to a contract are rejected until it is unpaused.
+        ContractPaused {
+            /// The address of the paused contract.
+            address: H160,
+        },
+        /// A contract can be called again.
+        ContractUnpaused {
+            /// The address of the unpaused contract.
+            address: H160,
+        },
+        /// A deployment bond has been held for a newly deployed contract.
+        DeploymentBondHeld {
+            /// The address of the deployed contract.
+            contract: H160,
+            /// The address from which the bond is held.
+            deployer: H160,
+            /// The amount held.
+            amount: BalanceOf<T>,
+        },
+        /// A contract has been marked as retired.
+        ContractRetired {
+            /// The address of the retired contract.
+            contract: H160,
+        },
+        /// A deployment bond has been released to its deployer.
+        DeploymentBondReleased {
+            /// The address of the contract the bond was held for.
+            contract: H160,
+            /// The address to which the bond has been released.
+            deployer: H160,
+            /// The amount released.
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -68,15 +552,123 @@ pub mod pallet {
         AddressAlreadyHasDeployPermission,
         /// Tried to revoke deploy permission from an address not having it.
         AddressDoesNotHaveDeployPermission,
+        /// Tried to grant a deploy permission expiring at a block which is not in the future.
+        ExpiryNotInFuture,
+        /// Tried to allow an init code hash which is already allowed.
+        InitCodeHashAlreadyAllowed,
+        /// Tried to disallow an init code hash which is not allowed.
+        InitCodeHashNotAllowed,
+        /// Tried to approve a factory which is already approved.
+        FactoryAlreadyApproved,
+        /// Tried to revoke a factory which is not approved.
+        FactoryNotApproved,
+        /// Tried to add a manager which is already a manager.
+        AddressAlreadyManager,
+        /// Tried to remove a manager which is not a manager.
+        AddressNotManager,
+        /// Tried to set a quota limiting deployments per window with an empty window, or allowing
+        /// more than `MaxDeploysPerWindow` deployments per window.
+        InvalidDeployQuota,
+        /// Tried to remove the quota of an address not having one.
+        AddressDoesNotHaveDeployQuota,
+        /// The deployer has reached its maximum total number of deployments.
+        TotalDeployQuotaExceeded,
+        /// The deployer has reached its maximum number of deployments in the current window.
+        WindowDeployQuotaExceeded,
+        /// Deployments are frozen.
+        DeploymentsFrozen,
+        /// The address is not allowed to deploy contracts.
+        NotAllowedToDeploy,
+        /// The address has already a pending deploy permission request.
+        RequestAlreadyPending,
+        /// The pending deploy permission request queue is full.
+        TooManyPendingRequests,
+        /// The address has no pending deploy permission request.
+        RequestNotFound,
+        /// The init code is longer than allowed by the bytecode policy.
+        InitCodeTooLarge,
+        /// The deployed runtime code is longer than allowed by the bytecode policy.
+        RuntimeCodeTooLarge,
+        /// The deployed runtime code contains an opcode disallowed by the bytecode policy.
+        DisallowedOpcode,
+        /// Tried to pause a contract which is already paused.
+        ContractAlreadyPaused,
+        /// Tried to unpause a contract which is not paused.
+        ContractNotPaused,
+        /// The called contract is paused.
+        ContractIsPaused,
+        /// No deployment bond is held for the contract.
+        NoDeploymentBond,
+        /// Tried to release the deployment bond of a contract which still has code.
+        ContractHasCode,
+        /// The deployer cannot afford the deployment bond.
+        CannotAffordDeploymentBond,
     }
 
     #[pallet::storage]
-    pub type Deployers<T> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+    pub type Deployers<T> =
+        StorageMap<_, Blake2_128Concat, H160, DeployerInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    /// The last deployer inspected by the expiry pruning in `on_idle`; the next run resumes
+    /// right after it.
+    #[pallet::storage]
+    pub type PruneCursor<T> = StorageValue<_, H160, OptionQuery>;
+
+    /// Keccak256 hashes of init codes which can be deployed by any account.
+    #[pallet::storage]
+    pub type AllowedInitCodeHashes<T> = StorageMap<_, Identity, H256, (), OptionQuery>;
+
+    /// Contracts allowed to deploy other contracts through `CREATE`/`CREATE2`.
+    #[pallet::storage]
+    pub type ApprovedFactories<T> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+    /// Accounts allowed to grant and revoke deploy permissions on behalf of `ManagerOrigin`.
+    #[pallet::storage]
+    pub type Managers<T> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+    /// Optional limits on the number of contracts an address can deploy.
+    #[pallet::storage]
+    pub type DeployQuotas<T> =
+        StorageMap<_, Blake2_128Concat, H160, DeployQuota<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Deployments performed by addresses having a quota.
+    #[pallet::storage]
+    pub type DeployCounters<T> =
+        StorageMap<_, Blake2_128Concat, H160, DeployCounter<BlockNumberFor<T>>, ValueQuery>;
+
+    /// The policy currently applied to contract deployments.
+    #[pallet::storage]
+    pub type Mode<T> = StorageValue<_, DeploymentMode, ValueQuery>;
+
+    /// Contracts deployed through the permissioned runner, keyed by contract address.
+    #[pallet::storage]
+    pub type DeployedContracts<T> =
+        StorageMap<_, Blake2_128Concat, H160, DeployedContract<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Deploy permission requests waiting for a decision, keyed by requesting address.
+    #[pallet::storage]
+    pub type PendingRequests<T: Config> =
+        CountedStorageMap<_, Blake2_128Concat, H160, DeployRequestOf<T>, OptionQuery>;
+
+    /// Constraints on the bytecode of contracts deployed through the permissioned runner.
+    #[pallet::storage]
+    pub type CodePolicy<T> = StorageValue<_, BytecodePolicy, ValueQuery>;
+
+    /// Contracts whose top-level calls are rejected.
+    #[pallet::storage]
+    pub type PausedContracts<T> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+    /// Deployment bonds held, keyed by contract address.
+    #[pallet::storage]
+    pub type ContractBonds<T: Config> =
+        StorageMap<_, Blake2_128Concat, H160, ContractBond<BalanceOf<T>>, OptionQuery>;
 
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
         pub deployers: Vec<H160>,
+        pub managers: Vec<H160>,
+        pub mode: DeploymentMode,
         _phantom: PhantomData<T>,
     }
 
@@ -84,8 +676,34 @@ pub mod pallet {
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             self.deployers.iter().for_each(|deployer| {
-                Deployers::<T>::insert(deployer, ());
+                Deployers::<T>::insert(deployer, DeployerInfo::default());
+            });
+            self.managers.iter().for_each(|manager| {
+                Managers::<T>::insert(manager, ());
             });
+            Mode::<T>::put(self.mode);
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let base_weight = T::WeightInfo::prune_expired_deployers(0);
+            let per_item_weight =
+                T::WeightInfo::prune_expired_deployers(1).saturating_sub(base_weight);
+            let Some(available_weight) = remaining_weight.checked_sub(&base_weight) else {
+                return Weight::zero();
+            };
+            let max_items = available_weight
+                .checked_div_per_component(&per_item_weight)
+                .unwrap_or(u64::MAX)
+                .min(T::MaxPrunedPerBlock::get() as u64) as u32;
+            if max_items == 0 {
+                return Weight::zero();
+            }
+
+            let visited = Self::prune_expired_deployers(max_items);
+            T::WeightInfo::prune_expired_deployers(visited)
         }
     }
 
@@ -94,20 +712,385 @@ pub mod pallet {
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::grant_deploy_permission())]
         pub fn grant_deploy_permission(origin: OriginFor<T>, address: H160) -> DispatchResult {
-            ensure_root(origin)?;
-            if !Deployers::<T>::contains_key(address) {
-                Deployers::<T>::insert(address, ());
-                Self::deposit_event(Event::<T>::DeployPermissionGranted { address });
+            let granted_by = Self::ensure_manager(origin)?;
+            Self::do_grant_deploy_permission(address, None, granted_by, None)
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::revoke_deploy_permission())]
+        pub fn revoke_deploy_permission(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            Self::do_revoke_deploy_permission(address)
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::grant_temporary_deploy_permission())]
+        pub fn grant_temporary_deploy_permission(
+            origin: OriginFor<T>,
+            address: H160,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let granted_by = Self::ensure_manager(origin)?;
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ExpiryNotInFuture
+            );
+            Self::do_grant_deploy_permission(address, Some(expires_at), granted_by, None)
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::allow_init_code_hash())]
+        pub fn allow_init_code_hash(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if !AllowedInitCodeHashes::<T>::contains_key(code_hash) {
+                AllowedInitCodeHashes::<T>::insert(code_hash, ());
+                Self::deposit_event(Event::<T>::InitCodeHashAllowed { code_hash });
+                Ok(())
+            } else {
+                Err(Error::<T>::InitCodeHashAlreadyAllowed)?
+            }
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::disallow_init_code_hash())]
+        pub fn disallow_init_code_hash(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if AllowedInitCodeHashes::<T>::contains_key(code_hash) {
+                AllowedInitCodeHashes::<T>::remove(code_hash);
+                Self::deposit_event(Event::<T>::InitCodeHashDisallowed { code_hash });
+                Ok(())
+            } else {
+                Err(Error::<T>::InitCodeHashNotAllowed)?
+            }
+        }
+
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::approve_factory())]
+        pub fn approve_factory(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if !ApprovedFactories::<T>::contains_key(address) {
+                ApprovedFactories::<T>::insert(address, ());
+                Self::deposit_event(Event::<T>::FactoryApproved { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::FactoryAlreadyApproved)?
+            }
+        }
+
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::revoke_factory())]
+        pub fn revoke_factory(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if ApprovedFactories::<T>::contains_key(address) {
+                ApprovedFactories::<T>::remove(address);
+                Self::deposit_event(Event::<T>::FactoryRevoked { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::FactoryNotApproved)?
+            }
+        }
+
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::add_manager())]
+        pub fn add_manager(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if !Managers::<T>::contains_key(address) {
+                Managers::<T>::insert(address, ());
+                Self::deposit_event(Event::<T>::ManagerAdded { address });
                 Ok(())
             } else {
+                Err(Error::<T>::AddressAlreadyManager)?
+            }
+        }
+
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_manager())]
+        pub fn remove_manager(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if Managers::<T>::contains_key(address) {
+                Managers::<T>::remove(address);
+                Self::deposit_event(Event::<T>::ManagerRemoved { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::AddressNotManager)?
+            }
+        }
+
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_deploy_quota())]
+        pub fn set_deploy_quota(
+            origin: OriginFor<T>,
+            address: H160,
+            quota: DeployQuota<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            ensure!(
+                quota.max_per_window.is_none_or(|max_per_window| {
+                    !quota.window.is_zero() && max_per_window <= MaxDeploysPerWindow::get()
+                }),
+                Error::<T>::InvalidDeployQuota
+            );
+            DeployQuotas::<T>::insert(address, quota.clone());
+            // The quota applies to deployments from now on.
+            DeployCounters::<T>::remove(address);
+            Self::deposit_event(Event::<T>::DeployQuotaSet { address, quota });
+            Ok(())
+        }
+
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::remove_deploy_quota())]
+        pub fn remove_deploy_quota(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            if DeployQuotas::<T>::contains_key(address) {
+                DeployQuotas::<T>::remove(address);
+                DeployCounters::<T>::remove(address);
+                Self::deposit_event(Event::<T>::DeployQuotaRemoved { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::AddressDoesNotHaveDeployQuota)?
+            }
+        }
+
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_deployment_mode())]
+        pub fn set_deployment_mode(origin: OriginFor<T>, mode: DeploymentMode) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            Mode::<T>::put(mode);
+            Self::deposit_event(Event::<T>::ModeChanged { mode });
+            Ok(())
+        }
+
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::request_deploy_permission())]
+        pub fn request_deploy_permission(
+            origin: OriginFor<T>,
+            metadata: Option<H256>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let address = T::AccountIdToAddress::convert(who.clone());
+            ensure!(
+                !Self::is_deployer(&address),
+                Error::<T>::AddressAlreadyHasDeployPermission
+            );
+            ensure!(
+                !PendingRequests::<T>::contains_key(address),
+                Error::<T>::RequestAlreadyPending
+            );
+            ensure!(
+                PendingRequests::<T>::count() < T::MaxPendingRequests::get(),
+                Error::<T>::TooManyPendingRequests
+            );
+
+            let deposit = T::RequestDeposit::get();
+            T::Currency::hold(&HoldReason::DeployPermissionRequest.into(), &who, deposit)?;
+            PendingRequests::<T>::insert(
+                address,
+                DeployRequest {
+                    who,
+                    deposit,
+                    metadata,
+                    requested_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::<T>::DeployPermissionRequested {
+                address,
+                metadata,
+                deposit,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::approve_deploy_permission_request())]
+        pub fn approve_deploy_permission_request(
+            origin: OriginFor<T>,
+            address: H160,
+        ) -> DispatchResult {
+            let granted_by = Self::ensure_manager(origin)?;
+            let request = PendingRequests::<T>::take(address).ok_or(Error::<T>::RequestNotFound)?;
+            T::Currency::release(
+                &HoldReason::DeployPermissionRequest.into(),
+                &request.who,
+                request.deposit,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::<T>::DeployPermissionRequestApproved { address });
+            // The permission may have been granted directly while the request was pending.
+            if !Self::is_deployer(&address) {
+                Self::do_grant_deploy_permission(address, None, granted_by, request.metadata)?;
+            }
+            Ok(())
+        }
+
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::reject_deploy_permission_request())]
+        pub fn reject_deploy_permission_request(
+            origin: OriginFor<T>,
+            address: H160,
+        ) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            let request = PendingRequests::<T>::take(address).ok_or(Error::<T>::RequestNotFound)?;
+            let slashed = T::Currency::burn_held(
+                &HoldReason::DeployPermissionRequest.into(),
+                &request.who,
+                request.deposit,
+                Precision::BestEffort,
+                Fortitude::Force,
+            )?;
+            Self::deposit_event(Event::<T>::DeployPermissionRequestRejected { address, slashed });
+            Ok(())
+        }
+
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_bytecode_policy())]
+        pub fn set_bytecode_policy(origin: OriginFor<T>, policy: BytecodePolicy) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            CodePolicy::<T>::put(policy.clone());
+            Self::deposit_event(Event::<T>::BytecodePolicySet { policy });
+            Ok(())
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::pause_contract())]
+        pub fn pause_contract(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if !PausedContracts::<T>::contains_key(address) {
+                PausedContracts::<T>::insert(address, ());
+                Self::deposit_event(Event::<T>::ContractPaused { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::ContractAlreadyPaused)?
+            }
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::unpause_contract())]
+        pub fn unpause_contract(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            if PausedContracts::<T>::contains_key(address) {
+                PausedContracts::<T>::remove(address);
+                Self::deposit_event(Event::<T>::ContractUnpaused { address });
+                Ok(())
+            } else {
+                Err(Error::<T>::ContractNotPaused)?
+            }
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_deployer_metadata())]
+        pub fn set_deployer_metadata(
+            origin: OriginFor<T>,
+            address: H160,
+            label: DeployerLabel,
+            notes_hash: Option<H256>,
+        ) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            Deployers::<T>::try_mutate(address, |info| {
+                let info = info
+                    .as_mut()
+                    .ok_or(Error::<T>::AddressDoesNotHaveDeployPermission)?;
+                info.label = label.clone();
+                info.notes_hash = notes_hash;
+                Ok::<_, DispatchError>(())
+            })?;
+            Self::deposit_event(Event::<T>::DeployerMetadataSet {
+                address,
+                label,
+                notes_hash,
+            });
+            Ok(())
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::grant_deploy_permissions(addresses.len() as u32))]
+        pub fn grant_deploy_permissions(
+            origin: OriginFor<T>,
+            addresses: BoundedVec<H160, T::MaxBatchSize>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            let granted_by = Self::ensure_manager(origin)?;
+            Self::do_batch(addresses, mode, |address| {
+                Self::do_grant_deploy_permission(address, None, granted_by, None)
+            })
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::revoke_deploy_permissions(addresses.len() as u32))]
+        pub fn revoke_deploy_permissions(
+            origin: OriginFor<T>,
+            addresses: BoundedVec<H160, T::MaxBatchSize>,
+            mode: BatchMode,
+        ) -> DispatchResult {
+            Self::ensure_manager(origin)?;
+            Self::do_batch(addresses, mode, Self::do_revoke_deploy_permission)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::retire_contract())]
+        pub fn retire_contract(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            Self::release_bond(contract)?;
+            Self::deposit_event(Event::<T>::ContractRetired { contract });
+            Ok(())
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::release_deployment_bond())]
+        pub fn release_deployment_bond(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                !T::HasCode::contains(&contract),
+                Error::<T>::ContractHasCode
+            );
+            Self::release_bond(contract)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Ensures `origin` is either `ManagerOrigin` or a signed deployer manager.
+        fn ensure_manager(origin: OriginFor<T>) -> Result<Granter, DispatchError> {
+            match T::ManagerOrigin::try_origin(origin) {
+                Ok(_) => Ok(Granter::Admin),
+                Err(origin) => {
+                    let address = T::AccountIdToAddress::convert(ensure_signed(origin)?);
+                    ensure!(
+                        Managers::<T>::contains_key(address),
+                        DispatchError::BadOrigin
+                    );
+                    Ok(Granter::Manager(address))
+                }
+            }
+        }
+
+        fn do_grant_deploy_permission(
+            address: H160,
+            expires_at: Option<BlockNumberFor<T>>,
+            granted_by: Granter,
+            notes_hash: Option<H256>,
+        ) -> DispatchResult {
+            // An expired permission which has not been pruned yet can be granted again.
+            if Self::is_deployer(&address) {
                 Err(Error::<T>::AddressAlreadyHasDeployPermission)?
             }
+            Deployers::<T>::insert(
+                address,
+                DeployerInfo {
+                    expires_at,
+                    granted_by,
+                    granted_at: frame_system::Pallet::<T>::block_number(),
+                    label: Default::default(),
+                    notes_hash,
+                },
+            );
+            Self::deposit_event(Event::<T>::DeployPermissionGranted {
+                address,
+                expires_at,
+                granted_by,
+            });
+            Ok(())
         }
 
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::revoke_deploy_permission())]
-        pub fn revoke_deploy_permission(origin: OriginFor<T>, address: H160) -> DispatchResult {
-            ensure_root(origin)?;
+        fn do_revoke_deploy_permission(address: H160) -> DispatchResult {
             if Deployers::<T>::contains_key(address) {
                 Deployers::<T>::remove(address);
                 Self::deposit_event(Event::<T>::DeployPermissionRevoked { address });
@@ -116,6 +1099,171 @@ pub mod pallet {
                 Err(Error::<T>::AddressDoesNotHaveDeployPermission)?
             }
         }
+
+        /// Applies `f` to every address: with [`BatchMode::AllOrNothing`] the first error is
+        /// returned, so that the whole call is reverted; with [`BatchMode::SkipFailed`] it is
+        /// reported by a `BatchItemSkipped` event.
+        fn do_batch(
+            addresses: BoundedVec<H160, T::MaxBatchSize>,
+            mode: BatchMode,
+            f: impl Fn(H160) -> DispatchResult,
+        ) -> DispatchResult {
+            for address in addresses {
+                match (f(address), mode) {
+                    (Ok(()), _) => {}
+                    (Err(error), BatchMode::AllOrNothing) => return Err(error),
+                    (Err(error), BatchMode::SkipFailed) => {
+                        Self::deposit_event(Event::<T>::BatchItemSkipped { address, error })
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        /// Returns `true` if `address` has a deploy permission which has not expired yet.
+        pub fn is_deployer(address: &H160) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            Deployers::<T>::get(address).is_some_and(|info| !info.is_expired(&now))
+        }
+
+        /// Returns at most `limit` addresses having a deploy permission which has not expired
+        /// yet, in storage order and starting right after `start_after`, if any.
+        pub fn deployers(
+            start_after: Option<H160>,
+            limit: u32,
+        ) -> Vec<(H160, DeployerInfo<BlockNumberFor<T>>)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let iter = match start_after {
+                Some(address) => Deployers::<T>::iter_from(Deployers::<T>::hashed_key_for(address)),
+                None => Deployers::<T>::iter(),
+            };
+            iter.filter(|(_, info)| !info.is_expired(&now))
+                .take(limit as usize)
+                .collect()
+        }
+
+        fn release_bond(contract: H160) -> DispatchResult {
+            let bond = ContractBonds::<T>::take(contract).ok_or(Error::<T>::NoDeploymentBond)?;
+            T::Currency::release(
+                &HoldReason::DeploymentBond.into(),
+                &T::AddressToAccountId::convert(bond.deployer),
+                bond.amount,
+                Precision::BestEffort,
+            )?;
+            Self::deposit_event(Event::<T>::DeploymentBondReleased {
+                contract,
+                deployer: bond.deployer,
+                amount: bond.amount,
+            });
+            Ok(())
+        }
+
+        /// Returns the total deployment bonds held from `deployer`.
+        pub fn deployment_bonds(deployer: H160) -> BalanceOf<T> {
+            T::Currency::balance_on_hold(
+                &HoldReason::DeploymentBond.into(),
+                &T::AddressToAccountId::convert(deployer),
+            )
+        }
+
+        /// Returns the deploy permission requests waiting for a decision.
+        pub fn pending_requests() -> Vec<(H160, DeployRequestOf<T>)> {
+            PendingRequests::<T>::iter().collect()
+        }
+
+        /// Checks that `address` has not exhausted its deploy quota, if any.
+        pub fn check_deploy_quota(address: &H160) -> DispatchResult {
+            let Some(quota) = DeployQuotas::<T>::get(address) else {
+                return Ok(());
+            };
+            let counter = DeployCounters::<T>::get(address);
+            if let Some(max_total) = quota.max_total {
+                ensure!(
+                    counter.total < max_total,
+                    Error::<T>::TotalDeployQuotaExceeded
+                );
+            }
+            if let Some(max_per_window) = quota.max_per_window {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(
+                    counter.in_window_at(quota.window, now) < max_per_window,
+                    Error::<T>::WindowDeployQuotaExceeded
+                );
+            }
+            Ok(())
+        }
+
+        /// Checks that `address` can afford the deployment bond held when it deploys a contract.
+        pub fn check_deployment_bond(address: &H160) -> DispatchResult {
+            let bond = T::DeploymentBond::get();
+            ensure!(
+                bond.is_zero()
+                    || T::Currency::can_hold(
+                        &HoldReason::DeploymentBond.into(),
+                        &T::AddressToAccountId::convert(*address),
+                        bond,
+                    ),
+                Error::<T>::CannotAffordDeploymentBond
+            );
+            Ok(())
+        }
+
+        /// Checks `init` against the bytecode policy.
+        pub fn check_init_code(init: &[u8]) -> DispatchResult {
+            if let Some(max_len) = CodePolicy::<T>::get().max_init_code_len {
+                ensure!(init.len() <= max_len as usize, Error::<T>::InitCodeTooLarge);
+            }
+            Ok(())
+        }
+
+        /// Checks the deployed runtime `code` against the bytecode policy.
+        pub fn check_deployed_code(code: &[u8]) -> DispatchResult {
+            let policy = CodePolicy::<T>::get();
+            if let Some(max_len) = policy.max_runtime_code_len {
+                ensure!(
+                    code.len() <= max_len as usize,
+                    Error::<T>::RuntimeCodeTooLarge
+                );
+            }
+            ensure!(
+                policy.first_disallowed_opcode(code).is_none(),
+                Error::<T>::DisallowedOpcode
+            );
+            Ok(())
+        }
+
+        /// Inspects at most `max_items` deployers, resuming from [`PruneCursor`], and removes the
+        /// expired ones. Returns the number of inspected deployers.
+        pub(crate) fn prune_expired_deployers(max_items: u32) -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut iter = match PruneCursor::<T>::take() {
+                Some(cursor) => Deployers::<T>::iter_from(Deployers::<T>::hashed_key_for(cursor)),
+                None => Deployers::<T>::iter(),
+            };
+
+            let mut visited = 0;
+            let mut last_visited = None;
+            let mut expired = Vec::new();
+            for (address, info) in iter.by_ref().take(max_items as usize) {
+                visited += 1;
+                last_visited = Some(address);
+                if info.is_expired(&now) {
+                    expired.push(address);
+                }
+            }
+            // When the whole budget has been used there may be more deployers to inspect,
+            // otherwise the next run starts again from the beginning of the map.
+            if visited == max_items {
+                PruneCursor::<T>::set(last_visited);
+            }
+
+            expired.into_iter().for_each(|address| {
+                Deployers::<T>::remove(address);
+                Self::deposit_event(Event::<T>::DeployPermissionExpired { address });
+            });
+
+            visited
+        }
     }
 }
 
@@ -123,10 +1271,156 @@ impl<T: Config> EnsureCreateOrigin<T> for Pallet<T> {
     type Error = DispatchError;
 
     fn check_create_origin(address: &H160) -> Result<(), Self::Error> {
-        if Deployers::<T>::contains_key(address) {
+        match Mode::<T>::get() {
+            DeploymentMode::Permissioned if Self::is_deployer(address) => Ok(()),
+            DeploymentMode::Permissioned => Err(Error::<T>::NotAllowedToDeploy.into()),
+            DeploymentMode::Open => Ok(()),
+            DeploymentMode::Frozen => Err(Error::<T>::DeploymentsFrozen.into()),
+        }
+    }
+
+    fn check_create(address: &H160, init: &[u8]) -> Result<(), Self::Error> {
+        Self::check_create_origin(address).or_else(|err| {
+            let code_hash = H256(sp_io::hashing::keccak_256(init));
+            // Allowed init codes are an exception to the permissioned mode only.
+            if Mode::<T>::get() == DeploymentMode::Permissioned
+                && AllowedInitCodeHashes::<T>::contains_key(code_hash)
+            {
+                Ok(())
+            } else {
+                Err(err)
+            }
+        })?;
+        Self::check_init_code(init)?;
+        Self::check_deploy_quota(address)?;
+        // Checked again when holding the bond after execution, which may have spent the funds.
+        Self::check_deployment_bond(address)
+    }
+
+    fn check_call(target: &H160) -> Result<(), Self::Error> {
+        if PausedContracts::<T>::contains_key(target) {
+            Err(Error::<T>::ContractIsPaused.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_runtime_code(code: impl FnOnce() -> Vec<u8>) -> Result<(), Self::Error> {
+        // Avoid loading the code when the policy does not constrain it.
+        if CodePolicy::<T>::get().constrains_runtime_code() {
+            Self::check_deployed_code(&code())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn note_create(
+        deployer: &H160,
+        contract: &H160,
+        init_code_hash: &H256,
+    ) -> Result<(), Self::Error> {
+        let bond = T::DeploymentBond::get();
+        if !bond.is_zero() {
+            T::Currency::hold(
+                &HoldReason::DeploymentBond.into(),
+                &T::AddressToAccountId::convert(*deployer),
+                bond,
+            )?;
+            ContractBonds::<T>::insert(
+                contract,
+                ContractBond {
+                    deployer: *deployer,
+                    amount: bond,
+                },
+            );
+            Self::deposit_event(Event::<T>::DeploymentBondHeld {
+                contract: *contract,
+                deployer: *deployer,
+                amount: bond,
+            });
+        }
+        let now = frame_system::Pallet::<T>::block_number();
+        if let Some(quota) = DeployQuotas::<T>::get(deployer) {
+            DeployCounters::<T>::mutate(deployer, |counter| counter.record(quota.window, now));
+        }
+        DeployedContracts::<T>::insert(
+            contract,
+            DeployedContract {
+                deployer: *deployer,
+                init_code_hash: *init_code_hash,
+                deployed_at: now,
+            },
+        );
+        Self::deposit_event(Event::<T>::ContractDeployed {
+            contract: *contract,
+            deployer: *deployer,
+            init_code_hash: *init_code_hash,
+        });
+        Ok(())
+    }
+
+    fn check_create_weight() -> Weight {
+        T::WeightInfo::check_create()
+    }
+
+    fn check_call_weight() -> Weight {
+        T::WeightInfo::check_call()
+    }
+}
+
+/// Prefix of the code of an EOA which has delegated its code through an EIP-7702 authorization,
+/// followed by the address of the delegate.
+pub const DELEGATION_DESIGNATOR: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// Tells whether an address has EVM code, to be used as `Config::HasCode`.
+pub struct EvmHasCode<T>(PhantomData<T>);
+
+impl<T: pallet_evm::Config> Contains<H160> for EvmHasCode<T> {
+    fn contains(address: &H160) -> bool {
+        pallet_evm::AccountCodes::<T>::contains_key(address)
+    }
+}
+
+/// Filter for contracts deploying other contracts through the `CREATE`/`CREATE2` opcodes, to be
+/// used as `pallet_evm::Config::CreateInnerOriginFilter`: only approved factories and contracts
+/// having deploy permission are allowed.
+///
+/// The code an EOA delegates to through an EIP-7702 authorization runs as the EOA, and can be
+/// invoked by anyone. Hence such an EOA is the create origin of the contracts its delegated code
+/// deploys, but its deploy permission is not enough to allow them: it must be explicitly
+/// approved as a factory. Creates signed by the EOA itself are not affected.
+///
+/// Only the create origin is checked: `pallet_evm` provides neither the init code nor the outcome
+/// of inner creates. Hence the bytecode policy, deploy quotas, the record of deployed contracts
+/// and deployment bonds, enforced by [`runner::PermissionedDeploy`] on top-level creates, do not
+/// apply to the contracts a factory deploys: approving a factory delegates them to it.
+pub struct EnsureInnerCreateOrigin<T>(PhantomData<T>);
+
+impl<T: Config + pallet_evm::Config> EnsureInnerCreateOrigin<T> {
+    /// Returns `true` if `address` is an EOA which has delegated its code.
+    fn is_delegated(address: &H160) -> bool {
+        let code = pallet_evm::AccountCodes::<T>::get(address);
+        code.len() == DELEGATION_DESIGNATOR.len() + H160::len_bytes()
+            && code.starts_with(&DELEGATION_DESIGNATOR)
+    }
+}
+
+impl<T: Config + pallet_evm::Config> pallet_evm::EnsureCreateOrigin<T>
+    for EnsureInnerCreateOrigin<T>
+{
+    fn check_create_origin(address: &H160) -> Result<(), pallet_evm::Error<T>> {
+        let allowed = match Mode::<T>::get() {
+            DeploymentMode::Permissioned => {
+                ApprovedFactories::<T>::contains_key(address)
+                    || (Pallet::<T>::is_deployer(address) && !Self::is_delegated(address))
+            }
+            DeploymentMode::Open => true,
+            DeploymentMode::Frozen => false,
+        };
+        if allowed {
             Ok(())
         } else {
-            Err(DispatchError::Other("Not allowed to deploy"))
+            Err(pallet_evm::Error::<T>::CreateOriginNotAllowed)
         }
     }
 }
@@ -135,4 +1429,46 @@ pub trait EnsureCreateOrigin<T> {
     type Error: Into<DispatchError>;
 
     fn check_create_origin(address: &H160) -> Result<(), Self::Error>;
+
+    /// Checks whether `address` is allowed to deploy the given `init` code. By default only the
+    /// origin is checked.
+    fn check_create(address: &H160, _init: &[u8]) -> Result<(), Self::Error> {
+        Self::check_create_origin(address)
+    }
+
+    /// Checks whether `target` can be called by a top-level call. By default any target can.
+    fn check_call(_target: &H160) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Checks the runtime code of a successfully deployed contract, lazily provided by `code`. On
+    /// failure the deployment is reverted and reported as a failed execution, still charging the
+    /// gas used. By default any code is accepted.
+    fn check_runtime_code(_code: impl FnOnce() -> Vec<u8>) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called after `deployer` has successfully deployed `contract` from init code hashing to
+    /// `init_code_hash`. On failure the deployment is reverted as for
+    /// [`Self::check_runtime_code`].
+    fn note_create(
+        _deployer: &H160,
+        _contract: &H160,
+        _init_code_hash: &H256,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The worst-case weight of [`Self::check_create`], [`Self::check_runtime_code`] and
+    /// [`Self::note_create`], which run outside of the EVM and are charged to the create they
+    /// check. By default nothing is charged.
+    fn check_create_weight() -> Weight {
+        Weight::zero()
+    }
+
+    /// The worst-case weight of [`Self::check_call`], charged to the call it checks. By default
+    /// nothing is charged.
+    fn check_call_weight() -> Weight {
+        Weight::zero()
+    }
 }
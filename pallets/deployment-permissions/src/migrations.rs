@@ -0,0 +1,169 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the deployment permissions pallet.
+
+use crate::*;
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::OptionQuery,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{ensure, sp_runtime::TryRuntimeError};
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{storage_alias, Blake2_128Concat};
+
+    /// Information stored for every deployer in storage version 1 and 2.
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct DeployerInfo<BlockNumber> {
+        /// The block from which the deploy permission is no longer valid, if any.
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    /// `Deployers` as of storage version 1 and 2.
+    #[storage_alias]
+    pub type Deployers<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, H160, DeployerInfo<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Converts every `Deployers` entry from the unit value to a [`DeployerInfo`] without expiry.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Deployers::<T>::translate::<(), _>(|_, ()| {
+                translated += 1;
+                Some(DeployerInfo::default())
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Deployers::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let expected = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("cannot decode pre-upgrade state"))?;
+            let migrated = Deployers::<T>::iter_values()
+                .filter(|info| info.expires_at.is_none())
+                .count() as u32;
+            ensure!(
+                expected == migrated,
+                "some deployers have not been migrated correctly"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates the pallet storage from version 0 to version 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+
+    /// Introduces `PausedContracts`, which starts empty: there is nothing to translate.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            Weight::zero()
+        }
+    }
+
+    /// Migrates the pallet storage from version 1 to version 2, only bumping the storage version.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Converts every `Deployers` entry to the richer [`crate::DeployerInfo`], keeping its expiry.
+    /// Permissions granted before this version are recorded as granted by the admin at block 0,
+    /// without label or notes.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Deployers::<T>::translate::<v1::DeployerInfo<BlockNumberFor<T>>, _>(|_, old| {
+                translated += 1;
+                Some(DeployerInfo {
+                    expires_at: old.expires_at,
+                    ..Default::default()
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let deployers: Vec<(H160, Option<BlockNumberFor<T>>)> = v1::Deployers::<T>::iter()
+                .map(|(address, info)| (address, info.expires_at))
+                .collect();
+            Ok(deployers.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let expected = Vec::<(H160, Option<BlockNumberFor<T>>)>::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("cannot decode pre-upgrade state"))?;
+            ensure!(
+                expected.len() == Deployers::<T>::iter_keys().count(),
+                "the number of deployers has changed"
+            );
+            for (address, expires_at) in expected {
+                let info = Deployers::<T>::get(address)
+                    .ok_or(TryRuntimeError::Other("a deployer has not been migrated"))?;
+                ensure!(
+                    info.expires_at == expires_at && info.granted_by == Granter::Admin,
+                    "a deployer has not been migrated correctly"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrates the pallet storage from version 2 to version 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -14,8 +14,13 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate as pallet_deploy_permissions;
-use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureRoot;
+use sp_core::H160;
+use sp_runtime::{traits::Convert, BuildStorage};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -40,22 +45,84 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type PalletDeployPermissions = pallet_deploy_permissions::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances::Pallet<Test>;
 }
 
 // System pallet configuration
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+/// Deposit held for every deploy permission request.
+pub const REQUEST_DEPOSIT: u64 = 10;
+/// Initial balance of the accounts funded in [`new_test_ext`].
+pub const INITIAL_BALANCE: u64 = 100;
+
+pub struct AccountIdToAddress;
+
+impl Convert<u64, H160> for AccountIdToAddress {
+    fn convert(account: u64) -> H160 {
+        H160::from_low_u64_be(account)
+    }
+}
+
+pub struct AddressToAccountId;
+
+impl Convert<H160, u64> for AddressToAccountId {
+    fn convert(address: H160) -> u64 {
+        address.to_low_u64_be()
+    }
+}
+
+parameter_types! {
+    /// Addresses having code according to [`HasCode`].
+    pub static ContractsWithCode: Vec<H160> = Vec::new();
+    /// Bond held for every deployed contract, none unless set by the test.
+    pub static DeploymentBond: u64 = 0;
+}
+
+pub struct HasCode;
+
+impl Contains<H160> for HasCode {
+    fn contains(address: &H160) -> bool {
+        ContractsWithCode::get().contains(address)
+    }
 }
 
 impl pallet_deploy_permissions::Config for Test {
+    type ManagerOrigin = EnsureRoot<u64>;
+    type AccountIdToAddress = AccountIdToAddress;
+    type AddressToAccountId = AddressToAccountId;
+    type MaxPrunedPerBlock = ConstU32<2>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type RequestDeposit = ConstU64<REQUEST_DEPOSIT>;
+    type MaxPendingRequests = ConstU32<2>;
+    type MaxBatchSize = ConstU32<3>;
+    type DeploymentBond = DeploymentBond;
+    type HasCode = HasCode;
     type WeightInfo = ();
 }
 
 // Test externalities initialization
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).map(|account| (account, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
 }
@@ -16,9 +16,14 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use ethereum::AuthorizationList;
-use frame_support::{sp_runtime::DispatchError, weights::Weight};
+use fp_evm::{AccountProvider, UsedGas};
+use frame_support::{
+    sp_runtime::{traits::UniqueSaturatedInto, DispatchError},
+    weights::Weight,
+};
 use pallet_evm::{
-    runner::Runner as RunnerT, BalanceOf, Config, EvmConfig, FeeCalculator, RunnerError,
+    runner::Runner as RunnerT, AddressMapping, BalanceOf, CallInfo, Config, CreateInfo, EvmConfig,
+    ExitError, ExitReason, FeeCalculator, GasWeightMapping, OnChargeEVMTransaction, RunnerError,
 };
 use sp_core::{H160, H256, U256};
 
@@ -54,6 +59,171 @@ where
     }
 }
 
+/// Prefix of the exit reason of a create rejected by the permission checks.
+pub const DEPLOYMENT_REJECTED: &str = "deployment rejected";
+
+/// Prefix of the exit reason of a call rejected by the permission checks.
+pub const CALL_REJECTED: &str = "call rejected";
+
+/// Code of the `InvalidTransaction::Custom` error reported when validating an Ethereum
+/// transaction rejected by the permission checks, above the codes used by `pallet_ethereum`.
+pub const REJECTED_TRANSACTION_CODE: u8 = 200;
+
+/// Reports a create rejected by the permission checks as a failed execution instead of a
+/// runtime error, whose reason does not survive the runtime API boundary: RPC clients then get
+/// a readable error for `eth_call` and `eth_estimateGas`.
+pub fn rejected_create_info(error: DispatchError) -> CreateInfo {
+    let reason: &'static str = error.into();
+    CreateInfo {
+        exit_reason: ExitReason::Error(ExitError::Other(
+            alloc::format!("{DEPLOYMENT_REJECTED}: {reason}").into(),
+        )),
+        value: H160::zero(),
+        used_gas: UsedGas {
+            standard: U256::zero(),
+            effective: U256::zero(),
+        },
+        weight_info: None,
+        logs: Vec::new(),
+    }
+}
+
+/// Reports a call rejected by the permission checks as a failed execution, see
+/// [`rejected_create_info`].
+pub fn rejected_call_info(error: DispatchError) -> CallInfo {
+    let reason: &'static str = error.into();
+    CallInfo {
+        exit_reason: ExitReason::Error(ExitError::Other(
+            alloc::format!("{CALL_REJECTED}: {reason}").into(),
+        )),
+        value: Vec::new(),
+        used_gas: UsedGas {
+            standard: U256::zero(),
+            effective: U256::zero(),
+        },
+        weight_info: None,
+        logs: Vec::new(),
+    }
+}
+
+impl<T, R, C> PermissionedDeploy<T, R, C>
+where
+    T: Config,
+    R: RunnerT<T>,
+    C: EnsureCreateOrigin<T>,
+{
+    /// Charges `weight`, spent by the permission checks outside of the EVM, to the transaction
+    /// they check: its ref time is deducted from the weight the execution can use, and its proof
+    /// size is added to the base proof size the EVM charges as gas.
+    fn charge_checks(
+        weight: Weight,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+    ) -> (Option<Weight>, Option<u64>) {
+        let weight_limit = weight_limit.map(|limit| {
+            Weight::from_parts(
+                limit.ref_time().saturating_sub(weight.ref_time()),
+                limit.proof_size(),
+            )
+        });
+        let proof_size_base_cost =
+            proof_size_base_cost.map(|cost| cost.saturating_add(weight.proof_size()));
+        (weight_limit, proof_size_base_cost)
+    }
+
+    /// Runs `create` and, if it succeeds, checks the runtime code of the created contract and
+    /// records the deployment. A rejected deployment is reverted but still fails as an execution:
+    /// the fee for the gas used is charged and the nonce of `source` is bumped, so that it cannot
+    /// be replayed for free.
+    fn create_checked(
+        source: &H160,
+        init_code_hash: &H256,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        is_transactional: bool,
+        create: impl FnOnce() -> Result<CreateInfo, RunnerError<R::Error>>,
+    ) -> Result<CreateInfo, RunnerError<PermissionedDeployError<T, R, C>>> {
+        sp_io::storage::start_transaction();
+        let info = match create() {
+            Ok(info) => info,
+            Err(err) => {
+                sp_io::storage::commit_transaction();
+                return Err(RunnerError {
+                    error: PermissionedDeployError::Runner(err.error),
+                    weight: err.weight,
+                });
+            }
+        };
+        let checked = if matches!(info.exit_reason, ExitReason::Succeed(_)) {
+            C::check_runtime_code(|| pallet_evm::AccountCodes::<T>::get(info.value))
+                .and_then(|()| C::note_create(source, &info.value, init_code_hash))
+        } else {
+            Ok(())
+        };
+        let Err(err) = checked else {
+            sp_io::storage::commit_transaction();
+            return Ok(info);
+        };
+
+        sp_io::storage::rollback_transaction();
+        let charged = Self::charge_rejected_create(
+            source,
+            info.used_gas.effective,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            is_transactional,
+        );
+        if charged.is_err() {
+            return Err(RunnerError {
+                error: PermissionedDeployError::Permission(err),
+                weight: T::GasWeightMapping::gas_to_weight(
+                    info.used_gas.standard.unique_saturated_into(),
+                    true,
+                ),
+            });
+        }
+        Ok(CreateInfo {
+            value: info.value,
+            used_gas: info.used_gas,
+            weight_info: info.weight_info,
+            ..rejected_create_info(err.into())
+        })
+    }
+
+    /// Charges the fee for `used_gas` and bumps the nonce of `source`, as the underlying runner
+    /// does for a failed execution.
+    fn charge_rejected_create(
+        source: &H160,
+        used_gas: U256,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        is_transactional: bool,
+    ) -> Result<(), pallet_evm::Error<T>> {
+        let (base_fee, _) = T::FeeCalculator::min_gas_price();
+        // Same fee per gas as the runner: execution has already been validated.
+        let fee_per_gas = match (max_fee_per_gas, max_priority_fee_per_gas, is_transactional) {
+            (Some(max_fee), _, true) if max_fee.is_zero() => U256::zero(),
+            (Some(_), None, _) => base_fee,
+            (Some(max_fee), Some(max_priority_fee), _) => {
+                base_fee.saturating_add(max_fee.saturating_sub(base_fee).min(max_priority_fee))
+            }
+            (None, _, _) => U256::zero(),
+        };
+        let fee = used_gas.saturating_mul(fee_per_gas);
+
+        let withdrawn = T::OnChargeTransaction::withdraw_fee(source, fee)?;
+        let tip = T::OnChargeTransaction::correct_and_deposit_fee(
+            source,
+            fee,
+            used_gas.saturating_mul(base_fee),
+            withdrawn,
+        );
+        T::OnChargeTransaction::pay_priority_fee(tip);
+        T::AccountProvider::inc_account_nonce(&T::AddressMapping::into_account_id(*source));
+        Ok(())
+    }
+}
+
 pub type RunnerOf<T> = <T as pallet_evm::Config>::Runner;
 pub type RunnerErrorOf<T> = RunnerError<<RunnerOf<T> as RunnerT<T>>::Error>;
 
@@ -82,6 +252,23 @@ where
         proof_size_base_cost: Option<u64>,
         evm_config: &EvmConfig,
     ) -> Result<(), pallet_evm::RunnerError<Self::Error>> {
+        // Reject creates and calls already when validating, so that they do not enter the pool.
+        let (_, weight) = T::FeeCalculator::min_gas_price();
+        match target {
+            None => C::check_create(&source, &input),
+            Some(target) => C::check_call(&target),
+        }
+        .map_err(|err| RunnerError {
+            error: PermissionedDeployError::Permission(err),
+            weight,
+        })?;
+        let checks_weight = match target {
+            None => C::check_create_weight(),
+            Some(_) => C::check_call_weight(),
+        };
+        let (weight_limit, proof_size_base_cost) =
+            Self::charge_checks(checks_weight, weight_limit, proof_size_base_cost);
+
         R::validate(
             source,
             target,
@@ -121,6 +308,14 @@ where
         proof_size_base_cost: Option<u64>,
         config: &EvmConfig,
     ) -> Result<pallet_evm::CallInfo, pallet_evm::RunnerError<Self::Error>> {
+        let (_, weight) = T::FeeCalculator::min_gas_price();
+        C::check_call(&target).map_err(|err| RunnerError {
+            error: PermissionedDeployError::Permission(err),
+            weight,
+        })?;
+        let (weight_limit, proof_size_base_cost) =
+            Self::charge_checks(C::check_call_weight(), weight_limit, proof_size_base_cost);
+
         R::call(
             source,
             target,
@@ -161,31 +356,39 @@ where
         config: &EvmConfig,
     ) -> Result<pallet_evm::CreateInfo, pallet_evm::RunnerError<Self::Error>> {
         let (_, weight) = T::FeeCalculator::min_gas_price();
-        C::check_create_origin(&source).map_err(|err| RunnerError {
+        C::check_create(&source, &init).map_err(|err| RunnerError {
             error: PermissionedDeployError::Permission(err),
             weight,
         })?;
+        let init_code_hash = H256(sp_io::hashing::keccak_256(&init));
+        let (weight_limit, proof_size_base_cost) =
+            Self::charge_checks(C::check_create_weight(), weight_limit, proof_size_base_cost);
 
-        R::create(
-            source,
-            init,
-            value,
-            gas_limit,
+        Self::create_checked(
+            &source,
+            &init_code_hash,
             max_fee_per_gas,
             max_priority_fee_per_gas,
-            nonce,
-            access_list,
-            authorization_list,
             is_transactional,
-            validate,
-            weight_limit,
-            proof_size_base_cost,
-            config,
+            || {
+                R::create(
+                    source,
+                    init,
+                    value,
+                    gas_limit,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    nonce,
+                    access_list,
+                    authorization_list,
+                    is_transactional,
+                    validate,
+                    weight_limit,
+                    proof_size_base_cost,
+                    config,
+                )
+            },
         )
-        .map_err(|err| RunnerError {
-            error: PermissionedDeployError::Runner(err.error),
-            weight: err.weight,
-        })
     }
 
     fn create2(
@@ -206,32 +409,40 @@ where
         config: &EvmConfig,
     ) -> Result<pallet_evm::CreateInfo, pallet_evm::RunnerError<Self::Error>> {
         let (_, weight) = T::FeeCalculator::min_gas_price();
-        C::check_create_origin(&source).map_err(|err| RunnerError {
+        C::check_create(&source, &init).map_err(|err| RunnerError {
             error: PermissionedDeployError::Permission(err),
             weight,
         })?;
+        let init_code_hash = H256(sp_io::hashing::keccak_256(&init));
+        let (weight_limit, proof_size_base_cost) =
+            Self::charge_checks(C::check_create_weight(), weight_limit, proof_size_base_cost);
 
-        R::create2(
-            source,
-            init,
-            salt,
-            value,
-            gas_limit,
+        Self::create_checked(
+            &source,
+            &init_code_hash,
             max_fee_per_gas,
             max_priority_fee_per_gas,
-            nonce,
-            access_list,
-            authorization_list,
             is_transactional,
-            validate,
-            weight_limit,
-            proof_size_base_cost,
-            config,
+            || {
+                R::create2(
+                    source,
+                    init,
+                    salt,
+                    value,
+                    gas_limit,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    nonce,
+                    access_list,
+                    authorization_list,
+                    is_transactional,
+                    validate,
+                    weight_limit,
+                    proof_size_base_cost,
+                    config,
+                )
+            },
         )
-        .map_err(|err| RunnerError {
-            error: PermissionedDeployError::Runner(err.error),
-            weight: err.weight,
-        })
     }
 
     fn create_force_address(
@@ -252,34 +463,43 @@ where
         force_address: H160,
     ) -> Result<fp_evm::CreateInfo, RunnerError<Self::Error>> {
         let (_, weight) = T::FeeCalculator::min_gas_price();
-        C::check_create_origin(&source).map_err(|err| RunnerError {
+        C::check_create(&source, &init).map_err(|err| RunnerError {
             error: PermissionedDeployError::Permission(err),
             weight,
         })?;
+        let init_code_hash = H256(sp_io::hashing::keccak_256(&init));
+        let (weight_limit, proof_size_base_cost) =
+            Self::charge_checks(C::check_create_weight(), weight_limit, proof_size_base_cost);
 
-        R::create_force_address(
-            source,
-            init,
-            value,
-            gas_limit,
+        Self::create_checked(
+            &source,
+            &init_code_hash,
             max_fee_per_gas,
             max_priority_fee_per_gas,
-            nonce,
-            access_list,
-            authorization_list,
             is_transactional,
-            validate,
-            weight_limit,
-            proof_size_base_cost,
-            config,
-            force_address,
+            || {
+                R::create_force_address(
+                    source,
+                    init,
+                    value,
+                    gas_limit,
+                    max_fee_per_gas,
+                    max_priority_fee_per_gas,
+                    nonce,
+                    access_list,
+                    authorization_list,
+                    is_transactional,
+                    validate,
+                    weight_limit,
+                    proof_size_base_cost,
+                    config,
+                    force_address,
+                )
+            },
         )
-        .map_err(|err| RunnerError {
-            error: PermissionedDeployError::Runner(err.error),
-            weight: err.weight,
-        })
     }
 }
+
 #[cfg(test)]
 mod mock {
     use super::*;
@@ -332,6 +552,11 @@ mod mock {
             type Error = sp_runtime::DispatchError;
 
             fn check_create_origin(address: &H160) -> Result<(), sp_runtime::DispatchError>;
+            fn note_create(
+                deployer: &H160,
+                contract: &H160,
+                init_code_hash: &H256,
+            ) -> Result<(), sp_runtime::DispatchError>;
         }
     }
 
@@ -444,6 +669,15 @@ mod mock {
 
     }
 
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        use sp_runtime::BuildStorage;
+
+        frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap()
+            .into()
+    }
+
     #[derive(Educe)]
     #[educe(Debug, Default, Clone)]
     pub struct ValidateArgs {
@@ -525,8 +759,8 @@ mod mock {
 
 #[cfg(test)]
 mod permissioned_runner {
-    use fp_evm::UsedGas;
-    use pallet_evm::{CallInfo, CreateInfo, ExitReason, ExitSucceed};
+    use fp_evm::{AccountProvider, UsedGas};
+    use pallet_evm::{CallInfo, CreateInfo, ExitReason, ExitRevert, ExitSucceed};
 
     use super::*;
     use std::sync::Mutex;
@@ -568,12 +802,31 @@ mod permissioned_runner {
         logs: vec![],
     };
 
+    /// Permissions whose checks always pass, at the cost of `DUMMY_WEIGHT`.
+    struct MeteredPermissions;
+
+    impl EnsureCreateOrigin<mock::Test> for MeteredPermissions {
+        type Error = sp_runtime::DispatchError;
+
+        fn check_create_origin(_address: &H160) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn check_create_weight() -> Weight {
+            DUMMY_WEIGHT
+        }
+
+        fn check_call_weight() -> Weight {
+            DUMMY_WEIGHT
+        }
+    }
+
     mod validate_method {
         use super::*;
         use crate::runner::mock::ValidateArgs;
 
         #[test]
-        fn is_permissionless() {
+        fn is_permissionless_for_calls() {
             let _m = MTX.lock();
 
             let ctx_runner = mock::MockRunner::validate_context();
@@ -587,7 +840,10 @@ mod permissioned_runner {
                 .expect()
                 .returning(|_| Err(DUMMY_DISPATCH_ERROR));
 
-            let params = ValidateArgs::default();
+            let params = ValidateArgs {
+                target: Some(H160::repeat_byte(1)),
+                ..Default::default()
+            };
 
             assert!(PermissionedRunner::validate(
                 params.source,
@@ -608,6 +864,42 @@ mod permissioned_runner {
             .is_ok());
         }
 
+        #[test]
+        fn is_permissioned_for_creates() {
+            let _m = MTX.lock();
+
+            let ctx_deployment_permissions =
+                mock::MockDeploymentPermissions::check_create_origin_context();
+            ctx_deployment_permissions
+                .expect()
+                .returning(|_| Err(DUMMY_DISPATCH_ERROR));
+
+            let params = ValidateArgs::default();
+
+            let RunnerError { error, weight } = PermissionedRunner::validate(
+                params.source,
+                params.target,
+                params.input,
+                params.value,
+                params.gas_limit,
+                params.max_fee_per_gas,
+                params.max_priority_fee_per_gas,
+                params.nonce,
+                params.access_list,
+                Default::default(),
+                params.is_transactional,
+                params.weight_limit,
+                params.proof_size_base_cost,
+                &params.config,
+            )
+            .unwrap_err();
+            assert_eq!(
+                error,
+                PermissionedDeployError::Permission(DUMMY_DISPATCH_ERROR)
+            );
+            assert_eq!(weight, Weight::zero());
+        }
+
         #[test]
         fn routes_underlying_ok() {
             let _m = MTX.lock();
@@ -618,7 +910,10 @@ mod permissioned_runner {
                 .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(()))
                 .once();
 
-            let params = ValidateArgs::default();
+            let params = ValidateArgs {
+                target: Some(H160::repeat_byte(1)),
+                ..Default::default()
+            };
 
             assert!(PermissionedRunner::validate(
                 params.source,
@@ -649,7 +944,10 @@ mod permissioned_runner {
                 .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Err(DUMMY_RUNNER_ERROR))
                 .once();
 
-            let params = ValidateArgs::default();
+            let params = ValidateArgs {
+                target: Some(H160::repeat_byte(1)),
+                ..Default::default()
+            };
 
             let RunnerError { error, weight } = PermissionedRunner::validate(
                 params.source,
@@ -786,6 +1084,54 @@ mod permissioned_runner {
             );
             assert_eq!(weight, DUMMY_RUNNER_ERROR.weight);
         }
+
+        #[test]
+        fn charges_checks_to_the_call() {
+            let _m = MTX.lock();
+
+            const WEIGHT_LIMIT: Weight = Weight::from_parts(1_000, 2_000);
+            const PROOF_SIZE_BASE_COST: u64 = 100;
+
+            let ctx_runner = mock::MockRunner::call_context();
+            ctx_runner
+                .expect()
+                .withf(
+                    |_, _, _, _, _, _, _, _, _, _, _, _, weight_limit, proof_size_base_cost, _| {
+                        *weight_limit
+                            == Some(Weight::from_parts(
+                                WEIGHT_LIMIT.ref_time() - DUMMY_WEIGHT.ref_time(),
+                                WEIGHT_LIMIT.proof_size(),
+                            ))
+                            && *proof_size_base_cost
+                                == Some(PROOF_SIZE_BASE_COST + DUMMY_WEIGHT.proof_size())
+                    },
+                )
+                .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CALL_INFO))
+                .once();
+
+            let params = CallArgs::default();
+
+            assert!(
+                PermissionedDeploy::<mock::Test, mock::MockRunner, MeteredPermissions>::call(
+                    params.source,
+                    params.target,
+                    params.input,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    Some(WEIGHT_LIMIT),
+                    Some(PROOF_SIZE_BASE_COST),
+                    &params.config,
+                )
+                .is_ok()
+            );
+        }
     }
 
     mod create_method {
@@ -832,76 +1178,259 @@ mod permissioned_runner {
         fn routes_underlying_ok() {
             let _m = MTX.lock();
 
-            let ctx_runner = mock::MockRunner::create_context();
-            ctx_runner
-                .expect()
-                .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CREATE_INFO))
-                .once();
-
-            let ctx_deployment_permissions =
-                mock::MockDeploymentPermissions::check_create_origin_context();
-            ctx_deployment_permissions.expect().returning(|_| Ok(()));
+            mock::new_test_ext().execute_with(|| {
+                let ctx_runner = mock::MockRunner::create_context();
+                ctx_runner
+                    .expect()
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CREATE_INFO))
+                    .once();
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let ctx_note_create = mock::MockDeploymentPermissions::note_create_context();
+                ctx_note_create.expect().return_const(Ok(())).once();
+
+                let params = CreateArgs::default();
+
+                let create_info = PermissionedRunner::create(
+                    params.source,
+                    params.init,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .unwrap();
+                assert!(create_info == DUMMY_CREATE_INFO);
+            });
+        }
 
-            let params = CreateArgs::default();
+        #[test]
+        fn routes_underlying_err() {
+            let _m = MTX.lock();
 
-            let create_info = PermissionedRunner::create(
-                params.source,
-                params.init,
-                params.value,
-                params.gas_limit,
-                params.max_fee_per_gas,
-                params.max_priority_fee_per_gas,
-                params.nonce,
-                params.access_list,
-                Default::default(),
-                params.is_transactional,
-                params.validate,
-                params.weight_limit,
-                params.proof_size_base_cost,
-                &params.config,
-            )
-            .unwrap();
-            assert!(create_info == DUMMY_CREATE_INFO);
+            mock::new_test_ext().execute_with(|| {
+                let ctx_runner = mock::MockRunner::create_context();
+                ctx_runner
+                    .expect()
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Err(DUMMY_RUNNER_ERROR))
+                    .times(1);
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let params = CreateArgs::default();
+
+                let RunnerError { error, weight } = PermissionedRunner::create(
+                    params.source,
+                    params.init,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .unwrap_err();
+                assert_eq!(
+                    error,
+                    PermissionedDeployError::Runner(DUMMY_RUNNER_ERROR.error)
+                );
+                assert_eq!(weight, DUMMY_RUNNER_ERROR.weight);
+            });
         }
 
         #[test]
-        fn routes_underlying_err() {
+        fn does_not_note_reverted_create() {
             let _m = MTX.lock();
 
-            let ctx_runner = mock::MockRunner::create_context();
-            ctx_runner
-                .expect()
-                .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Err(DUMMY_RUNNER_ERROR))
-                .times(1);
+            mock::new_test_ext().execute_with(|| {
+                let ctx_runner = mock::MockRunner::create_context();
+                ctx_runner
+                    .expect()
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| {
+                        Ok(CreateInfo {
+                            exit_reason: ExitReason::Revert(ExitRevert::Reverted),
+                            ..DUMMY_CREATE_INFO
+                        })
+                    })
+                    .once();
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let ctx_note_create = mock::MockDeploymentPermissions::note_create_context();
+                ctx_note_create.expect().never();
+
+                let params = CreateArgs::default();
+
+                assert!(PermissionedRunner::create(
+                    params.source,
+                    params.init,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .is_ok());
+            });
+        }
 
-            let ctx_deployment_permissions =
-                mock::MockDeploymentPermissions::check_create_origin_context();
-            ctx_deployment_permissions.expect().returning(|_| Ok(()));
+        #[test]
+        fn charges_create_rejected_by_note_create() {
+            use frame_support::traits::fungible::{Inspect, Mutate};
 
-            let params = CreateArgs::default();
+            const BALANCE: u64 = 1_000_000_000_000_000_000;
+            const CONTRACT: H160 = H160::repeat_byte(2);
+            const USED_GAS: u64 = 21_000;
 
-            let RunnerError { error, weight } = PermissionedRunner::create(
-                params.source,
-                params.init,
-                params.value,
-                params.gas_limit,
-                params.max_fee_per_gas,
-                params.max_priority_fee_per_gas,
-                params.nonce,
-                params.access_list,
-                Default::default(),
-                params.is_transactional,
-                params.validate,
-                params.weight_limit,
-                params.proof_size_base_cost,
-                &params.config,
-            )
-            .unwrap_err();
-            assert_eq!(
-                error,
-                PermissionedDeployError::Runner(DUMMY_RUNNER_ERROR.error)
-            );
-            assert_eq!(weight, DUMMY_RUNNER_ERROR.weight);
+            let _m = MTX.lock();
+
+            mock::new_test_ext().execute_with(|| {
+                let params = CreateArgs::default();
+                let account =
+                    <mock::Test as Config>::AddressMapping::into_account_id(params.source);
+                mock::Balances::set_balance(&account, BALANCE);
+                let (base_fee, _) = <mock::Test as Config>::FeeCalculator::min_gas_price();
+
+                let ctx_runner = mock::MockRunner::create_context();
+                ctx_runner
+                    .expect()
+                    .returning(|source, _, _, _, _, _, _, _, _, _, _, _, _, _| {
+                        // Side effects of the execution, which must be reverted.
+                        frame_system::Pallet::<mock::Test>::inc_account_nonce(
+                            <mock::Test as Config>::AddressMapping::into_account_id(source),
+                        );
+                        pallet_evm::AccountCodes::<mock::Test>::insert(CONTRACT, vec![0x00]);
+                        Ok(CreateInfo {
+                            value: CONTRACT,
+                            used_gas: UsedGas {
+                                standard: USED_GAS.into(),
+                                effective: USED_GAS.into(),
+                            },
+                            ..DUMMY_CREATE_INFO
+                        })
+                    })
+                    .once();
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let ctx_note_create = mock::MockDeploymentPermissions::note_create_context();
+                ctx_note_create
+                    .expect()
+                    .return_const(Err(DUMMY_DISPATCH_ERROR))
+                    .once();
+
+                let create_info = PermissionedRunner::create(
+                    params.source,
+                    params.init,
+                    params.value,
+                    params.gas_limit,
+                    Some(base_fee),
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .unwrap();
+
+                assert_eq!(
+                    create_info.exit_reason,
+                    ExitReason::Error(ExitError::Other("deployment rejected: dummy error".into()))
+                );
+                assert_eq!(create_info.used_gas.effective, USED_GAS.into());
+                assert!(pallet_evm::AccountCodes::<mock::Test>::get(CONTRACT).is_empty());
+                assert_eq!(
+                    frame_system::Pallet::<mock::Test>::account_nonce(&account),
+                    1
+                );
+                assert_eq!(
+                    mock::Balances::balance(&account),
+                    BALANCE - USED_GAS * base_fee.as_u64()
+                );
+            });
+        }
+
+        #[test]
+        fn charges_checks_to_the_create() {
+            let _m = MTX.lock();
+
+            mock::new_test_ext().execute_with(|| {
+                const WEIGHT_LIMIT: Weight = Weight::from_parts(1_000, 2_000);
+                const PROOF_SIZE_BASE_COST: u64 = 100;
+
+                let ctx_runner = mock::MockRunner::create_context();
+                ctx_runner
+                    .expect()
+                    .withf(
+                        |_, _, _, _, _, _, _, _, _, _, _, weight_limit, proof_size_base_cost, _| {
+                            *weight_limit
+                                == Some(Weight::from_parts(
+                                    WEIGHT_LIMIT.ref_time() - DUMMY_WEIGHT.ref_time(),
+                                    WEIGHT_LIMIT.proof_size(),
+                                ))
+                                && *proof_size_base_cost
+                                    == Some(PROOF_SIZE_BASE_COST + DUMMY_WEIGHT.proof_size())
+                        },
+                    )
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CREATE_INFO))
+                    .once();
+
+                let params = CreateArgs::default();
+
+                assert!(
+                    PermissionedDeploy::<mock::Test, mock::MockRunner, MeteredPermissions>::create(
+                        params.source,
+                        params.init,
+                        params.value,
+                        params.gas_limit,
+                        params.max_fee_per_gas,
+                        params.max_priority_fee_per_gas,
+                        params.nonce,
+                        params.access_list,
+                        Default::default(),
+                        params.is_transactional,
+                        params.validate,
+                        Some(WEIGHT_LIMIT),
+                        Some(PROOF_SIZE_BASE_COST),
+                        &params.config,
+                    )
+                    .is_ok()
+                );
+            });
         }
     }
 
@@ -951,78 +1480,87 @@ mod permissioned_runner {
         fn routes_underlying_ok() {
             let _m = MTX.lock();
 
-            let ctx_runner = mock::MockRunner::create2_context();
-            ctx_runner
-                .expect()
-                .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CREATE_INFO))
-                .once();
-
-            let ctx_deployment_permissions =
-                mock::MockDeploymentPermissions::check_create_origin_context();
-            ctx_deployment_permissions.expect().returning(|_| Ok(()));
-
-            let params = Create2Args::default();
-
-            let create2_info = PermissionedRunner::create2(
-                params.source,
-                params.init,
-                params.salt,
-                params.value,
-                params.gas_limit,
-                params.max_fee_per_gas,
-                params.max_priority_fee_per_gas,
-                params.nonce,
-                params.access_list,
-                Default::default(),
-                params.is_transactional,
-                params.validate,
-                params.weight_limit,
-                params.proof_size_base_cost,
-                &params.config,
-            )
-            .unwrap();
-            assert!(create2_info == DUMMY_CREATE_INFO);
+            mock::new_test_ext().execute_with(|| {
+                let ctx_runner = mock::MockRunner::create2_context();
+                ctx_runner
+                    .expect()
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _, _| Ok(DUMMY_CREATE_INFO))
+                    .once();
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let ctx_note_create = mock::MockDeploymentPermissions::note_create_context();
+                ctx_note_create.expect().return_const(Ok(())).once();
+
+                let params = Create2Args::default();
+
+                let create2_info = PermissionedRunner::create2(
+                    params.source,
+                    params.init,
+                    params.salt,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .unwrap();
+                assert!(create2_info == DUMMY_CREATE_INFO);
+            });
         }
 
         #[test]
         fn routes_underlying_err() {
             let _m = MTX.lock();
 
-            let ctx_runner = mock::MockRunner::create2_context();
-            ctx_runner
-                .expect()
-                .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _, _| Err(DUMMY_RUNNER_ERROR))
-                .once();
-
-            let ctx_deployment_permissions =
-                mock::MockDeploymentPermissions::check_create_origin_context();
-            ctx_deployment_permissions.expect().returning(|_| Ok(()));
-
-            let params = Create2Args::default();
-
-            let RunnerError { error, weight } = PermissionedRunner::create2(
-                params.source,
-                params.init,
-                params.salt,
-                params.value,
-                params.gas_limit,
-                params.max_fee_per_gas,
-                params.max_priority_fee_per_gas,
-                params.nonce,
-                params.access_list,
-                Default::default(),
-                params.is_transactional,
-                params.validate,
-                params.weight_limit,
-                params.proof_size_base_cost,
-                &params.config,
-            )
-            .unwrap_err();
-            assert_eq!(
-                error,
-                PermissionedDeployError::Runner(DUMMY_RUNNER_ERROR.error)
-            );
-            assert_eq!(weight, DUMMY_RUNNER_ERROR.weight);
+            mock::new_test_ext().execute_with(|| {
+                let ctx_runner = mock::MockRunner::create2_context();
+                ctx_runner
+                    .expect()
+                    .returning(|_, _, _, _, _, _, _, _, _, _, _, _, _, _, _| {
+                        Err(DUMMY_RUNNER_ERROR)
+                    })
+                    .once();
+
+                let ctx_deployment_permissions =
+                    mock::MockDeploymentPermissions::check_create_origin_context();
+                ctx_deployment_permissions.expect().returning(|_| Ok(()));
+
+                let params = Create2Args::default();
+
+                let RunnerError { error, weight } = PermissionedRunner::create2(
+                    params.source,
+                    params.init,
+                    params.salt,
+                    params.value,
+                    params.gas_limit,
+                    params.max_fee_per_gas,
+                    params.max_priority_fee_per_gas,
+                    params.nonce,
+                    params.access_list,
+                    Default::default(),
+                    params.is_transactional,
+                    params.validate,
+                    params.weight_limit,
+                    params.proof_size_base_cost,
+                    &params.config,
+                )
+                .unwrap_err();
+                assert_eq!(
+                    error,
+                    PermissionedDeployError::Runner(DUMMY_RUNNER_ERROR.error)
+                );
+                assert_eq!(weight, DUMMY_RUNNER_ERROR.weight);
+            });
         }
     }
 }
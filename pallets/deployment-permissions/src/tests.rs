@@ -15,7 +15,12 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    sp_runtime::traits::Convert,
+    traits::{Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
 
 mod grant_deploy_permission {
     use super::*;
@@ -43,7 +48,14 @@ mod grant_deploy_permission {
                 RuntimeOrigin::root(),
                 address
             ));
-            System::assert_last_event(Event::DeployPermissionGranted { address }.into());
+            System::assert_last_event(
+                Event::DeployPermissionGranted {
+                    address,
+                    expires_at: None,
+                    granted_by: Granter::Admin,
+                }
+                .into(),
+            );
         });
     }
 
@@ -63,18 +75,152 @@ mod grant_deploy_permission {
         })
     }
 
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let manager = AccountIdToAddress::convert(caller);
+            Managers::<Test>::insert(manager, ());
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::grant_deploy_permission(
+                RuntimeOrigin::signed(caller),
+                address
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(address).map(|info| (info.granted_by, info.granted_at)),
+                Some((Granter::Manager(manager), 1))
+            );
+            System::assert_last_event(
+                Event::DeployPermissionGranted {
+                    address,
+                    expires_at: None,
+                    granted_by: Granter::Manager(manager),
+                }
+                .into(),
+            );
+        });
+    }
+
     #[test]
     fn errors_if_address_already_has_deploy_permission() {
         new_test_ext().execute_with(|| {
             System::set_block_number(1);
             let address = H160::repeat_byte(42);
-            Deployers::<Test>::insert(address, ());
+            Deployers::<Test>::insert(address, DeployerInfo::default());
             assert_noop!(
                 PalletDeployPermissions::grant_deploy_permission(RuntimeOrigin::root(), address),
                 Error::<Test>::AddressAlreadyHasDeployPermission
             );
         })
     }
+
+    #[test]
+    fn overwrites_expired_deploy_permission() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(
+                address,
+                DeployerInfo {
+                    expires_at: Some(10),
+                    ..Default::default()
+                },
+            );
+            assert_ok!(PalletDeployPermissions::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(address),
+                Some(DeployerInfo {
+                    granted_at: 10,
+                    ..Default::default()
+                })
+            );
+        })
+    }
+}
+
+mod grant_temporary_deploy_permission {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_with_expiry() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::grant_temporary_deploy_permission(
+                RuntimeOrigin::root(),
+                address,
+                10
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(address),
+                Some(DeployerInfo {
+                    expires_at: Some(10),
+                    granted_by: Granter::Admin,
+                    granted_at: 1,
+                    label: Default::default(),
+                    notes_hash: None,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn emits_correct_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::grant_temporary_deploy_permission(
+                RuntimeOrigin::root(),
+                address,
+                10
+            ));
+            System::assert_last_event(
+                Event::DeployPermissionGranted {
+                    address,
+                    expires_at: Some(10),
+                    granted_by: Granter::Admin,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::repeat_byte(42);
+            assert_noop!(
+                PalletDeployPermissions::grant_temporary_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    address,
+                    10
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_expiry_is_not_in_the_future() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let address = H160::repeat_byte(42);
+            assert_noop!(
+                PalletDeployPermissions::grant_temporary_deploy_permission(
+                    RuntimeOrigin::root(),
+                    address,
+                    10
+                ),
+                Error::<Test>::ExpiryNotInFuture
+            );
+        })
+    }
 }
 
 mod revoke_deploy_permission {
@@ -85,7 +231,7 @@ mod revoke_deploy_permission {
         new_test_ext().execute_with(|| {
             System::set_block_number(1);
             let address = H160::repeat_byte(42);
-            Deployers::<Test>::insert(address, ());
+            Deployers::<Test>::insert(address, DeployerInfo::default());
             assert_ok!(PalletDeployPermissions::revoke_deploy_permission(
                 RuntimeOrigin::root(),
                 address
@@ -99,7 +245,7 @@ mod revoke_deploy_permission {
         new_test_ext().execute_with(|| {
             System::set_block_number(1);
             let address = H160::repeat_byte(42);
-            Deployers::<Test>::insert(address, ());
+            Deployers::<Test>::insert(address, DeployerInfo::default());
             assert_ok!(PalletDeployPermissions::revoke_deploy_permission(
                 RuntimeOrigin::root(),
                 address
@@ -114,7 +260,7 @@ mod revoke_deploy_permission {
             System::set_block_number(1);
             let caller: <Test as frame_system::Config>::AccountId = 1;
             let address = H160::repeat_byte(42);
-            Deployers::<Test>::insert(address, ());
+            Deployers::<Test>::insert(address, DeployerInfo::default());
             assert_noop!(
                 PalletDeployPermissions::revoke_deploy_permission(
                     RuntimeOrigin::signed(caller),
@@ -125,6 +271,22 @@ mod revoke_deploy_permission {
         })
     }
 
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(PalletDeployPermissions::revoke_deploy_permission(
+                RuntimeOrigin::signed(caller),
+                address
+            ));
+            assert!(!Deployers::<Test>::contains_key(address));
+        });
+    }
+
     #[test]
     fn errors_if_address_does_not_have_deploy_permission() {
         new_test_ext().execute_with(|| {
@@ -138,6 +300,208 @@ mod revoke_deploy_permission {
     }
 }
 
+mod grant_deploy_permissions {
+    use super::*;
+
+    fn addresses(bytes: &[u8]) -> BoundedVec<H160, <Test as Config>::MaxBatchSize> {
+        bytes
+            .iter()
+            .map(|byte| H160::repeat_byte(*byte))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn grants_every_address_and_emits_events() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let addresses = addresses(&[1, 2, 3]);
+            assert_ok!(PalletDeployPermissions::grant_deploy_permissions(
+                RuntimeOrigin::root(),
+                addresses.clone(),
+                BatchMode::AllOrNothing
+            ));
+            for address in addresses {
+                assert!(PalletDeployPermissions::is_deployer(&address));
+                System::assert_has_event(
+                    Event::DeployPermissionGranted {
+                        address,
+                        expires_at: None,
+                        granted_by: Granter::Admin,
+                    }
+                    .into(),
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let manager = AccountIdToAddress::convert(caller);
+            Managers::<Test>::insert(manager, ());
+            assert_ok!(PalletDeployPermissions::grant_deploy_permissions(
+                RuntimeOrigin::signed(caller),
+                addresses(&[1, 2]),
+                BatchMode::AllOrNothing
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(H160::repeat_byte(2)).map(|info| info.granted_by),
+                Some(Granter::Manager(manager))
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            assert_noop!(
+                PalletDeployPermissions::grant_deploy_permissions(
+                    RuntimeOrigin::signed(caller),
+                    addresses(&[1]),
+                    BatchMode::AllOrNothing
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn all_or_nothing_fails_if_any_address_fails() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Deployers::<Test>::insert(H160::repeat_byte(2), DeployerInfo::default());
+            assert_noop!(
+                PalletDeployPermissions::grant_deploy_permissions(
+                    RuntimeOrigin::root(),
+                    addresses(&[1, 2, 3]),
+                    BatchMode::AllOrNothing
+                ),
+                Error::<Test>::AddressAlreadyHasDeployPermission
+            );
+        })
+    }
+
+    #[test]
+    fn skip_failed_grants_remaining_addresses() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let skipped = H160::repeat_byte(2);
+            Deployers::<Test>::insert(skipped, DeployerInfo::default());
+            assert_ok!(PalletDeployPermissions::grant_deploy_permissions(
+                RuntimeOrigin::root(),
+                addresses(&[1, 2, 3]),
+                BatchMode::SkipFailed
+            ));
+            assert!(PalletDeployPermissions::is_deployer(&H160::repeat_byte(1)));
+            assert!(PalletDeployPermissions::is_deployer(&H160::repeat_byte(3)));
+            assert_eq!(
+                Deployers::<Test>::get(skipped),
+                Some(DeployerInfo::default())
+            );
+            System::assert_has_event(
+                Event::BatchItemSkipped {
+                    address: skipped,
+                    error: Error::<Test>::AddressAlreadyHasDeployPermission.into(),
+                }
+                .into(),
+            );
+        })
+    }
+}
+
+mod revoke_deploy_permissions {
+    use super::*;
+
+    fn addresses(bytes: &[u8]) -> BoundedVec<H160, <Test as Config>::MaxBatchSize> {
+        bytes
+            .iter()
+            .map(|byte| H160::repeat_byte(*byte))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn revokes_every_address_and_emits_events() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let addresses = addresses(&[1, 2, 3]);
+            for address in &addresses {
+                Deployers::<Test>::insert(address, DeployerInfo::default());
+            }
+            assert_ok!(PalletDeployPermissions::revoke_deploy_permissions(
+                RuntimeOrigin::root(),
+                addresses.clone(),
+                BatchMode::AllOrNothing
+            ));
+            for address in addresses {
+                assert!(!Deployers::<Test>::contains_key(address));
+                System::assert_has_event(Event::DeployPermissionRevoked { address }.into());
+            }
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Deployers::<Test>::insert(H160::repeat_byte(1), DeployerInfo::default());
+            assert_noop!(
+                PalletDeployPermissions::revoke_deploy_permissions(
+                    RuntimeOrigin::signed(caller),
+                    addresses(&[1]),
+                    BatchMode::AllOrNothing
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn all_or_nothing_fails_if_any_address_fails() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Deployers::<Test>::insert(H160::repeat_byte(1), DeployerInfo::default());
+            assert_noop!(
+                PalletDeployPermissions::revoke_deploy_permissions(
+                    RuntimeOrigin::root(),
+                    addresses(&[1, 2]),
+                    BatchMode::AllOrNothing
+                ),
+                Error::<Test>::AddressDoesNotHaveDeployPermission
+            );
+        })
+    }
+
+    #[test]
+    fn skip_failed_revokes_remaining_addresses() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Deployers::<Test>::insert(H160::repeat_byte(1), DeployerInfo::default());
+            assert_ok!(PalletDeployPermissions::revoke_deploy_permissions(
+                RuntimeOrigin::root(),
+                addresses(&[1, 2]),
+                BatchMode::SkipFailed
+            ));
+            assert!(!Deployers::<Test>::contains_key(H160::repeat_byte(1)));
+            System::assert_has_event(
+                Event::BatchItemSkipped {
+                    address: H160::repeat_byte(2),
+                    error: Error::<Test>::AddressDoesNotHaveDeployPermission.into(),
+                }
+                .into(),
+            );
+        })
+    }
+}
+
 mod check_create_origin {
     use super::*;
 
@@ -146,7 +510,7 @@ mod check_create_origin {
         new_test_ext().execute_with(|| {
             System::set_block_number(1);
             let address = H160::repeat_byte(42);
-            Deployers::<Test>::insert(address, ());
+            Deployers::<Test>::insert(address, DeployerInfo::default());
             assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create_origin(&address));
         })
     }
@@ -158,8 +522,1950 @@ mod check_create_origin {
             let address = H160::repeat_byte(42);
             assert_err!(
                 <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create_origin(&address),
-                DispatchError::Other("Not allowed to deploy")
+                Error::<Test>::NotAllowedToDeploy
+            );
+        })
+    }
+
+    #[test]
+    fn returns_ok_before_expiry() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(9);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(
+                address,
+                DeployerInfo {
+                    expires_at: Some(10),
+                    ..Default::default()
+                },
+            );
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create_origin(&address));
+        })
+    }
+
+    #[test]
+    fn returns_error_for_expired_address() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(
+                address,
+                DeployerInfo {
+                    expires_at: Some(10),
+                    ..Default::default()
+                },
+            );
+            assert_err!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create_origin(&address),
+                Error::<Test>::NotAllowedToDeploy
+            );
+        })
+    }
+}
+
+mod allow_init_code_hash {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let code_hash = H256::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::allow_init_code_hash(
+                RuntimeOrigin::root(),
+                code_hash
+            ));
+            assert!(AllowedInitCodeHashes::<Test>::contains_key(code_hash));
+            System::assert_last_event(Event::InitCodeHashAllowed { code_hash }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            assert_noop!(
+                PalletDeployPermissions::allow_init_code_hash(
+                    RuntimeOrigin::signed(caller),
+                    H256::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_already_allowed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let code_hash = H256::repeat_byte(42);
+            AllowedInitCodeHashes::<Test>::insert(code_hash, ());
+            assert_noop!(
+                PalletDeployPermissions::allow_init_code_hash(RuntimeOrigin::root(), code_hash),
+                Error::<Test>::InitCodeHashAlreadyAllowed
+            );
+        })
+    }
+}
+
+mod disallow_init_code_hash {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let code_hash = H256::repeat_byte(42);
+            AllowedInitCodeHashes::<Test>::insert(code_hash, ());
+            assert_ok!(PalletDeployPermissions::disallow_init_code_hash(
+                RuntimeOrigin::root(),
+                code_hash
+            ));
+            assert!(!AllowedInitCodeHashes::<Test>::contains_key(code_hash));
+            System::assert_last_event(Event::InitCodeHashDisallowed { code_hash }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let code_hash = H256::repeat_byte(42);
+            AllowedInitCodeHashes::<Test>::insert(code_hash, ());
+            assert_noop!(
+                PalletDeployPermissions::disallow_init_code_hash(
+                    RuntimeOrigin::signed(caller),
+                    code_hash
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_allowed() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::disallow_init_code_hash(
+                    RuntimeOrigin::root(),
+                    H256::repeat_byte(42)
+                ),
+                Error::<Test>::InitCodeHashNotAllowed
+            );
+        })
+    }
+}
+
+mod approve_factory {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::approve_factory(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(ApprovedFactories::<Test>::contains_key(address));
+            System::assert_last_event(Event::FactoryApproved { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            assert_noop!(
+                PalletDeployPermissions::approve_factory(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn cannot_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            assert_noop!(
+                PalletDeployPermissions::approve_factory(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_already_approved() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            ApprovedFactories::<Test>::insert(address, ());
+            assert_noop!(
+                PalletDeployPermissions::approve_factory(RuntimeOrigin::root(), address),
+                Error::<Test>::FactoryAlreadyApproved
+            );
+        })
+    }
+}
+
+mod revoke_factory {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            ApprovedFactories::<Test>::insert(address, ());
+            assert_ok!(PalletDeployPermissions::revoke_factory(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!ApprovedFactories::<Test>::contains_key(address));
+            System::assert_last_event(Event::FactoryRevoked { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::repeat_byte(42);
+            ApprovedFactories::<Test>::insert(address, ());
+            assert_noop!(
+                PalletDeployPermissions::revoke_factory(RuntimeOrigin::signed(caller), address),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_approved() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::revoke_factory(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::FactoryNotApproved
+            );
+        })
+    }
+}
+
+mod add_manager {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::add_manager(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(Managers::<Test>::contains_key(address));
+            System::assert_last_event(Event::ManagerAdded { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            assert_noop!(
+                PalletDeployPermissions::add_manager(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_already_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Managers::<Test>::insert(address, ());
+            assert_noop!(
+                PalletDeployPermissions::add_manager(RuntimeOrigin::root(), address),
+                Error::<Test>::AddressAlreadyManager
+            );
+        })
+    }
+}
+
+mod remove_manager {
+    use super::*;
+
+    #[test]
+    fn clears_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Managers::<Test>::insert(address, ());
+            assert_ok!(PalletDeployPermissions::remove_manager(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!Managers::<Test>::contains_key(address));
+            System::assert_last_event(Event::ManagerRemoved { address }.into());
+        });
+    }
+
+    #[test]
+    fn removed_manager_cannot_grant() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let manager = AccountIdToAddress::convert(caller);
+            Managers::<Test>::insert(manager, ());
+            assert_ok!(PalletDeployPermissions::remove_manager(
+                RuntimeOrigin::root(),
+                manager
+            ));
+            assert_noop!(
+                PalletDeployPermissions::grant_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::remove_manager(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::AddressNotManager
+            );
+        })
+    }
+}
+
+mod check_create {
+    use super::*;
+
+    const INIT_CODE: &[u8] = &[0x60, 0x00, 0x60, 0x00, 0xF3];
+
+    #[test]
+    fn returns_ok_for_whitelisted_address() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(
+                &address, INIT_CODE
+            ));
+        })
+    }
+
+    #[test]
+    fn returns_ok_for_allowed_init_code() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            AllowedInitCodeHashes::<Test>::insert(H256(sp_io::hashing::keccak_256(INIT_CODE)), ());
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(
+                &address, INIT_CODE
+            ));
+        })
+    }
+
+    #[test]
+    fn returns_error_for_non_whitelisted_address_and_unknown_init_code() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            AllowedInitCodeHashes::<Test>::insert(H256::repeat_byte(42), ());
+            assert_err!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(&address, INIT_CODE),
+                Error::<Test>::NotAllowedToDeploy
+            );
+        })
+    }
+
+    #[test]
+    fn returns_ok_if_deployer_can_afford_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            DeploymentBond::set(INITIAL_BALANCE / 2);
+            let address = AccountIdToAddress::convert(1);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(
+                &address, INIT_CODE
+            ));
+        })
+    }
+
+    #[test]
+    fn returns_error_if_deployer_cannot_afford_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            DeploymentBond::set(INITIAL_BALANCE + 1);
+            let address = AccountIdToAddress::convert(1);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_err!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(&address, INIT_CODE),
+                Error::<Test>::CannotAffordDeploymentBond
+            );
+        })
+    }
+}
+
+mod set_deploy_quota {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            let quota = DeployQuota {
+                max_total: Some(10),
+                max_per_window: Some(2),
+                window: 5,
+            };
+            assert_ok!(PalletDeployPermissions::set_deploy_quota(
+                RuntimeOrigin::root(),
+                address,
+                quota.clone()
+            ));
+            assert_eq!(DeployQuotas::<Test>::get(address), Some(quota.clone()));
+            System::assert_last_event(Event::DeployQuotaSet { address, quota }.into());
+        });
+    }
+
+    #[test]
+    fn resets_deployments_counted_by_previous_quota() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: Some(1),
+                    ..Default::default()
+                },
+            );
+            DeployCounters::<Test>::insert(
+                address,
+                DeployCounter {
+                    total: 1,
+                    recent: BoundedVec::truncate_from(vec![1]),
+                },
+            );
+            assert_ok!(PalletDeployPermissions::set_deploy_quota(
+                RuntimeOrigin::root(),
+                address,
+                DeployQuota {
+                    max_total: Some(1),
+                    ..Default::default()
+                }
+            ));
+            assert!(!DeployCounters::<Test>::contains_key(address));
+            assert_ok!(PalletDeployPermissions::check_deploy_quota(&address));
+        });
+    }
+
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::set_deploy_quota(
+                RuntimeOrigin::signed(caller),
+                address,
+                DeployQuota {
+                    max_total: Some(1),
+                    ..Default::default()
+                }
+            ));
+            assert!(DeployQuotas::<Test>::contains_key(address));
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            assert_noop!(
+                PalletDeployPermissions::set_deploy_quota(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42),
+                    DeployQuota::default()
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_window_is_empty() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::set_deploy_quota(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42),
+                    DeployQuota {
+                        max_total: None,
+                        max_per_window: Some(1),
+                        window: 0,
+                    }
+                ),
+                Error::<Test>::InvalidDeployQuota
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_window_allows_too_many_deployments() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::set_deploy_quota(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42),
+                    DeployQuota {
+                        max_total: None,
+                        max_per_window: Some(MaxDeploysPerWindow::get() + 1),
+                        window: 10,
+                    }
+                ),
+                Error::<Test>::InvalidDeployQuota
+            );
+        })
+    }
+}
+
+mod remove_deploy_quota {
+    use super::*;
+
+    #[test]
+    fn clears_storage_keys_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            DeployQuotas::<Test>::insert(address, DeployQuota::default());
+            DeployCounters::<Test>::insert(
+                address,
+                DeployCounter {
+                    total: 1,
+                    recent: BoundedVec::truncate_from(vec![1]),
+                },
+            );
+            assert_ok!(PalletDeployPermissions::remove_deploy_quota(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!DeployQuotas::<Test>::contains_key(address));
+            assert!(!DeployCounters::<Test>::contains_key(address));
+            System::assert_last_event(Event::DeployQuotaRemoved { address }.into());
+        });
+    }
+
+    #[test]
+    fn errors_if_address_does_not_have_quota() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::remove_deploy_quota(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::AddressDoesNotHaveDeployQuota
+            );
+        })
+    }
+}
+
+mod deploy_quota {
+    use super::*;
+
+    const INIT_CODE: &[u8] = &[0x60, 0x00, 0x60, 0x00, 0xF3];
+
+    fn deploy(address: &H160) -> Result<(), DispatchError> {
+        <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(address, INIT_CODE)?;
+        <Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+            address,
+            &H160::repeat_byte(1),
+            &H256(sp_io::hashing::keccak_256(INIT_CODE)),
+        )
+    }
+
+    #[test]
+    fn is_not_tracked_without_quota() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(deploy(&address));
+            assert!(!DeployCounters::<Test>::contains_key(address));
+        })
+    }
+
+    #[test]
+    fn limits_total_deployments() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: Some(2),
+                    ..Default::default()
+                },
+            );
+            assert_ok!(deploy(&address));
+            System::set_block_number(100);
+            assert_ok!(deploy(&address));
+            assert_err!(deploy(&address), Error::<Test>::TotalDeployQuotaExceeded);
+            assert_eq!(DeployCounters::<Test>::get(address).total, 2);
+        })
+    }
+
+    #[test]
+    fn limits_deployments_per_window() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: None,
+                    max_per_window: Some(2),
+                    window: 10,
+                },
+            );
+            assert_ok!(deploy(&address));
+            System::set_block_number(5);
+            assert_ok!(deploy(&address));
+            assert_err!(deploy(&address), Error::<Test>::WindowDeployQuotaExceeded);
+            System::set_block_number(11);
+            assert_ok!(deploy(&address));
+            assert_eq!(
+                DeployCounters::<Test>::get(address),
+                DeployCounter {
+                    total: 3,
+                    recent: BoundedVec::truncate_from(vec![5, 11]),
+                }
+            );
+        })
+    }
+
+    #[test]
+    fn limits_deployments_in_any_window() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: None,
+                    max_per_window: Some(2),
+                    window: 10,
+                },
+            );
+            assert_ok!(deploy(&address));
+            System::set_block_number(9);
+            assert_ok!(deploy(&address));
+            // Blocks 2 to 11 include the deployment at block 9 only.
+            System::set_block_number(11);
+            assert_ok!(deploy(&address));
+            // Blocks 3 to 12 include the deployments at blocks 9 and 11.
+            System::set_block_number(12);
+            assert_err!(deploy(&address), Error::<Test>::WindowDeployQuotaExceeded);
+            System::set_block_number(18);
+            assert_err!(deploy(&address), Error::<Test>::WindowDeployQuotaExceeded);
+            System::set_block_number(19);
+            assert_ok!(deploy(&address));
+        })
+    }
+
+    #[test]
+    fn does_not_grant_permission() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: Some(2),
+                    ..Default::default()
+                },
+            );
+            assert_err!(deploy(&address), Error::<Test>::NotAllowedToDeploy);
+        })
+    }
+}
+
+mod note_create {
+    use super::*;
+
+    #[test]
+    fn records_deployed_contract_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(3);
+            let deployer = H160::repeat_byte(42);
+            let contract = H160::repeat_byte(1);
+            let init_code_hash = H256::repeat_byte(2);
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+                &deployer,
+                &contract,
+                &init_code_hash,
+            ));
+            assert_eq!(
+                DeployedContracts::<Test>::get(contract),
+                Some(DeployedContract {
+                    deployer,
+                    init_code_hash,
+                    deployed_at: 3,
+                })
+            );
+            System::assert_last_event(
+                Event::ContractDeployed {
+                    contract,
+                    deployer,
+                    init_code_hash,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn holds_deployment_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            DeploymentBond::set(5);
+            let deployer = AccountIdToAddress::convert(1);
+            let contract = H160::repeat_byte(1);
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+                &deployer,
+                &contract,
+                &H256::repeat_byte(2),
+            ));
+            assert_eq!(
+                ContractBonds::<Test>::get(contract),
+                Some(ContractBond {
+                    deployer,
+                    amount: 5
+                })
+            );
+            assert_eq!(PalletDeployPermissions::deployment_bonds(deployer), 5);
+            assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - 5);
+            System::assert_has_event(
+                Event::DeploymentBondHeld {
+                    contract,
+                    deployer,
+                    amount: 5,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_deployer_cannot_pay_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            DeploymentBond::set(INITIAL_BALANCE + 1);
+            assert!(<Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+                &AccountIdToAddress::convert(1),
+                &H160::repeat_byte(1),
+                &H256::repeat_byte(2),
+            )
+            .is_err());
+        })
+    }
+}
+
+mod retire_contract {
+    use super::*;
+
+    /// Deploys `contract` from account 1, holding a bond of 5.
+    fn bonded_contract(contract: H160) {
+        DeploymentBond::set(5);
+        assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+            &AccountIdToAddress::convert(1),
+            &contract,
+            &H256::repeat_byte(2),
+        ));
+    }
+
+    #[test]
+    fn releases_bond_and_emits_events() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contract = H160::repeat_byte(42);
+            bonded_contract(contract);
+            assert_ok!(PalletDeployPermissions::retire_contract(
+                RuntimeOrigin::root(),
+                contract
+            ));
+            assert!(!ContractBonds::<Test>::contains_key(contract));
+            assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+            System::assert_has_event(
+                Event::DeploymentBondReleased {
+                    contract,
+                    deployer: AccountIdToAddress::convert(1),
+                    amount: 5,
+                }
+                .into(),
+            );
+            System::assert_last_event(Event::ContractRetired { contract }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contract = H160::repeat_byte(42);
+            bonded_contract(contract);
+            assert_noop!(
+                PalletDeployPermissions::retire_contract(RuntimeOrigin::signed(2), contract),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_without_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::retire_contract(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::NoDeploymentBond
+            );
+        })
+    }
+}
+
+mod release_deployment_bond {
+    use super::*;
+
+    /// Deploys `contract` from account 1, holding a bond of 5.
+    fn bonded_contract(contract: H160) {
+        DeploymentBond::set(5);
+        assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::note_create(
+            &AccountIdToAddress::convert(1),
+            &contract,
+            &H256::repeat_byte(2),
+        ));
+    }
+
+    #[test]
+    fn releases_bond_of_contract_without_code() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contract = H160::repeat_byte(42);
+            bonded_contract(contract);
+            assert_ok!(PalletDeployPermissions::release_deployment_bond(
+                RuntimeOrigin::signed(2),
+                contract
+            ));
+            assert!(!ContractBonds::<Test>::contains_key(contract));
+            assert_eq!(
+                PalletDeployPermissions::deployment_bonds(AccountIdToAddress::convert(1)),
+                0
+            );
+            System::assert_last_event(
+                Event::DeploymentBondReleased {
+                    contract,
+                    deployer: AccountIdToAddress::convert(1),
+                    amount: 5,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn errors_if_contract_has_code() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let contract = H160::repeat_byte(42);
+            bonded_contract(contract);
+            ContractsWithCode::set(vec![contract]);
+            assert_noop!(
+                PalletDeployPermissions::release_deployment_bond(
+                    RuntimeOrigin::signed(2),
+                    contract
+                ),
+                Error::<Test>::ContractHasCode
+            );
+        })
+    }
+
+    #[test]
+    fn errors_without_bond() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::release_deployment_bond(
+                    RuntimeOrigin::signed(2),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::NoDeploymentBond
+            );
+        })
+    }
+}
+
+mod set_deployment_mode {
+    use super::*;
+
+    #[test]
+    fn sets_storage_value_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_eq!(Mode::<Test>::get(), DeploymentMode::Permissioned);
+            assert_ok!(PalletDeployPermissions::set_deployment_mode(
+                RuntimeOrigin::root(),
+                DeploymentMode::Frozen
+            ));
+            assert_eq!(Mode::<Test>::get(), DeploymentMode::Frozen);
+            System::assert_last_event(
+                Event::ModeChanged {
+                    mode: DeploymentMode::Frozen,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            assert_noop!(
+                PalletDeployPermissions::set_deployment_mode(
+                    RuntimeOrigin::signed(caller),
+                    DeploymentMode::Open
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+}
+
+mod deployment_mode {
+    use super::*;
+
+    const INIT_CODE: &[u8] = &[0x60, 0x00, 0x60, 0x00, 0xF3];
+
+    fn check_create(address: &H160) -> Result<(), DispatchError> {
+        <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(address, INIT_CODE)
+    }
+
+    #[test]
+    fn open_mode_allows_anyone() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            Mode::<Test>::put(DeploymentMode::Open);
+            assert_ok!(check_create(&H160::repeat_byte(42)));
+        })
+    }
+
+    #[test]
+    fn open_mode_still_applies_quotas() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Mode::<Test>::put(DeploymentMode::Open);
+            DeployQuotas::<Test>::insert(
+                address,
+                DeployQuota {
+                    max_total: Some(0),
+                    ..Default::default()
+                },
+            );
+            assert_err!(
+                check_create(&address),
+                Error::<Test>::TotalDeployQuotaExceeded
+            );
+        })
+    }
+
+    #[test]
+    fn frozen_mode_rejects_deployers() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            Mode::<Test>::put(DeploymentMode::Frozen);
+            assert_err!(check_create(&address), Error::<Test>::DeploymentsFrozen);
+        })
+    }
+
+    #[test]
+    fn frozen_mode_rejects_allowed_init_code() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            AllowedInitCodeHashes::<Test>::insert(H256(sp_io::hashing::keccak_256(INIT_CODE)), ());
+            Mode::<Test>::put(DeploymentMode::Frozen);
+            assert_err!(
+                check_create(&H160::repeat_byte(42)),
+                Error::<Test>::DeploymentsFrozen
+            );
+        })
+    }
+}
+
+mod request_deploy_permission {
+    use super::*;
+
+    #[test]
+    fn holds_deposit_and_stores_request() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller = 1;
+            let address = AccountIdToAddress::convert(caller);
+            let metadata = Some(H256::repeat_byte(7));
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(caller),
+                metadata
+            ));
+            assert_eq!(
+                PendingRequests::<Test>::get(address),
+                Some(DeployRequest {
+                    who: caller,
+                    deposit: REQUEST_DEPOSIT,
+                    metadata,
+                    requested_at: 1,
+                })
+            );
+            assert_eq!(
+                Balances::free_balance(caller),
+                INITIAL_BALANCE - REQUEST_DEPOSIT
+            );
+            System::assert_last_event(
+                Event::DeployPermissionRequested {
+                    address,
+                    metadata,
+                    deposit: REQUEST_DEPOSIT,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn fails_if_already_deployer() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller = 1;
+            Deployers::<Test>::insert(AccountIdToAddress::convert(caller), DeployerInfo::default());
+            assert_noop!(
+                PalletDeployPermissions::request_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    None
+                ),
+                Error::<Test>::AddressAlreadyHasDeployPermission
+            );
+        });
+    }
+
+    #[test]
+    fn fails_if_already_pending() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller = 1;
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(caller),
+                None
+            ));
+            assert_noop!(
+                PalletDeployPermissions::request_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    None
+                ),
+                Error::<Test>::RequestAlreadyPending
+            );
+        });
+    }
+
+    #[test]
+    fn fails_if_queue_is_full() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            for caller in 1..=2 {
+                assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    None
+                ));
+            }
+            assert_noop!(
+                PalletDeployPermissions::request_deploy_permission(RuntimeOrigin::signed(3), None),
+                Error::<Test>::TooManyPendingRequests
+            );
+        });
+    }
+
+    #[test]
+    fn fails_without_funds_for_deposit() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller = 42;
+            assert!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(caller),
+                None
+            )
+            .is_err());
+            assert!(!PendingRequests::<Test>::contains_key(
+                AccountIdToAddress::convert(caller)
+            ));
+        });
+    }
+
+    #[test]
+    fn cannot_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletDeployPermissions::request_deploy_permission(RuntimeOrigin::root(), None),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
+
+mod approve_deploy_permission_request {
+    use super::*;
+
+    #[test]
+    fn grants_permission_and_releases_deposit() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let requester = 1;
+            let address = AccountIdToAddress::convert(requester);
+            let metadata = H256::repeat_byte(1);
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(requester),
+                Some(metadata)
+            ));
+            assert_ok!(PalletDeployPermissions::approve_deploy_permission_request(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!PendingRequests::<Test>::contains_key(address));
+            assert!(PalletDeployPermissions::is_deployer(&address));
+            assert_eq!(
+                Deployers::<Test>::get(address).and_then(|info| info.notes_hash),
+                Some(metadata)
+            );
+            assert_eq!(Balances::free_balance(requester), INITIAL_BALANCE);
+            System::assert_has_event(Event::DeployPermissionRequestApproved { address }.into());
+            System::assert_last_event(
+                Event::DeployPermissionGranted {
+                    address,
+                    expires_at: None,
+                    granted_by: Granter::Admin,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let manager = 2;
+            let manager_address = AccountIdToAddress::convert(manager);
+            Managers::<Test>::insert(manager_address, ());
+            let address = AccountIdToAddress::convert(1);
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(1),
+                None
+            ));
+            assert_ok!(PalletDeployPermissions::approve_deploy_permission_request(
+                RuntimeOrigin::signed(manager),
+                address
+            ));
+            System::assert_last_event(
+                Event::DeployPermissionGranted {
+                    address,
+                    expires_at: None,
+                    granted_by: Granter::Manager(manager_address),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn succeeds_if_permission_granted_meanwhile() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let requester = 1;
+            let address = AccountIdToAddress::convert(requester);
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(requester),
+                None
+            ));
+            assert_ok!(PalletDeployPermissions::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert_ok!(PalletDeployPermissions::approve_deploy_permission_request(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert_eq!(Balances::free_balance(requester), INITIAL_BALANCE);
+            System::assert_last_event(Event::DeployPermissionRequestApproved { address }.into());
+        });
+    }
+
+    #[test]
+    fn fails_if_no_request() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletDeployPermissions::approve_deploy_permission_request(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::RequestNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_be_invoked_by_requester() {
+        new_test_ext().execute_with(|| {
+            let requester = 1;
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(requester),
+                None
+            ));
+            assert_noop!(
+                PalletDeployPermissions::approve_deploy_permission_request(
+                    RuntimeOrigin::signed(requester),
+                    AccountIdToAddress::convert(requester)
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
+
+mod reject_deploy_permission_request {
+    use super::*;
+
+    #[test]
+    fn slashes_deposit_without_granting() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let requester = 1;
+            let address = AccountIdToAddress::convert(requester);
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(requester),
+                None
+            ));
+            let issuance = pallet_balances::TotalIssuance::<Test>::get();
+            assert_ok!(PalletDeployPermissions::reject_deploy_permission_request(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!PendingRequests::<Test>::contains_key(address));
+            assert!(!PalletDeployPermissions::is_deployer(&address));
+            assert_eq!(
+                Balances::free_balance(requester),
+                INITIAL_BALANCE - REQUEST_DEPOSIT
+            );
+            assert_eq!(
+                pallet_balances::TotalIssuance::<Test>::get(),
+                issuance - REQUEST_DEPOSIT
+            );
+            System::assert_last_event(
+                Event::DeployPermissionRequestRejected {
+                    address,
+                    slashed: REQUEST_DEPOSIT,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn frees_a_slot_in_the_queue() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            for caller in 1..=2 {
+                assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                    RuntimeOrigin::signed(caller),
+                    None
+                ));
+            }
+            assert_ok!(PalletDeployPermissions::reject_deploy_permission_request(
+                RuntimeOrigin::root(),
+                AccountIdToAddress::convert(1)
+            ));
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(3),
+                None
+            ));
+            assert_eq!(PalletDeployPermissions::pending_requests().len(), 2);
+        });
+    }
+
+    #[test]
+    fn fails_if_no_request() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                PalletDeployPermissions::reject_deploy_permission_request(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::RequestNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_be_invoked_by_non_manager() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PalletDeployPermissions::request_deploy_permission(
+                RuntimeOrigin::signed(1),
+                None
+            ));
+            assert_noop!(
+                PalletDeployPermissions::reject_deploy_permission_request(
+                    RuntimeOrigin::signed(2),
+                    AccountIdToAddress::convert(1)
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
+
+mod deployers {
+    use super::*;
+
+    #[test]
+    fn pages_through_all_deployers() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let addresses: Vec<_> = (1..=5).map(H160::repeat_byte).collect();
+            for address in &addresses {
+                Deployers::<Test>::insert(address, DeployerInfo::default());
+            }
+
+            let first = PalletDeployPermissions::deployers(None, 2);
+            assert_eq!(first.len(), 2);
+            let second = PalletDeployPermissions::deployers(first.last().map(|(a, _)| *a), 2);
+            assert_eq!(second.len(), 2);
+            let third = PalletDeployPermissions::deployers(second.last().map(|(a, _)| *a), 2);
+            assert_eq!(third.len(), 1);
+
+            let mut listed: Vec<_> = first
+                .into_iter()
+                .chain(second)
+                .chain(third)
+                .map(|(address, _)| address)
+                .collect();
+            listed.sort();
+            assert_eq!(listed, addresses);
+        });
+    }
+
+    #[test]
+    fn skips_expired_deployers() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let active = H160::repeat_byte(1);
+            let expired = H160::repeat_byte(2);
+            Deployers::<Test>::insert(
+                active,
+                DeployerInfo {
+                    expires_at: Some(11),
+                    ..Default::default()
+                },
+            );
+            Deployers::<Test>::insert(
+                expired,
+                DeployerInfo {
+                    expires_at: Some(10),
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(
+                PalletDeployPermissions::deployers(None, 10),
+                vec![(
+                    active,
+                    DeployerInfo {
+                        expires_at: Some(11),
+                        ..Default::default()
+                    }
+                )]
+            );
+        });
+    }
+}
+
+mod set_bytecode_policy {
+    use super::*;
+
+    fn policy() -> BytecodePolicy {
+        BytecodePolicy {
+            max_init_code_len: Some(100),
+            max_runtime_code_len: Some(50),
+            disallowed_opcodes: vec![0xff].try_into().unwrap(),
+        }
+    }
+
+    #[test]
+    fn sets_storage_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(PalletDeployPermissions::set_bytecode_policy(
+                RuntimeOrigin::root(),
+                policy()
+            ));
+            assert_eq!(CodePolicy::<Test>::get(), policy());
+            System::assert_last_event(Event::BytecodePolicySet { policy: policy() }.into());
+        });
+    }
+
+    #[test]
+    fn cannot_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            let caller = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            assert_noop!(
+                PalletDeployPermissions::set_bytecode_policy(
+                    RuntimeOrigin::signed(caller),
+                    policy()
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+}
+
+mod bytecode_policy {
+    use super::*;
+
+    const SELFDESTRUCT: u8 = 0xff;
+
+    fn set_policy(policy: BytecodePolicy) {
+        CodePolicy::<Test>::put(policy);
+    }
+
+    fn check_runtime_code(code: &[u8]) -> Result<(), DispatchError> {
+        <Pallet<Test> as EnsureCreateOrigin<Test>>::check_runtime_code(|| code.to_vec())
+    }
+
+    #[test]
+    fn default_policy_accepts_any_code() {
+        new_test_ext().execute_with(|| {
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(
+                &address,
+                &[0; 10_000]
+            ));
+            assert_ok!(check_runtime_code(&[SELFDESTRUCT; 10_000]));
+        });
+    }
+
+    #[test]
+    fn default_policy_does_not_load_runtime_code() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_runtime_code(|| unreachable!())
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_too_large_init_code() {
+        new_test_ext().execute_with(|| {
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            set_policy(BytecodePolicy {
+                max_init_code_len: Some(4),
+                ..Default::default()
+            });
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(
+                &address, &[0; 4]
+            ));
+            assert_err!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_create(&address, &[0; 5]),
+                Error::<Test>::InitCodeTooLarge
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_too_large_runtime_code() {
+        new_test_ext().execute_with(|| {
+            set_policy(BytecodePolicy {
+                max_runtime_code_len: Some(4),
+                ..Default::default()
+            });
+            assert_ok!(check_runtime_code(&[0; 4]));
+            assert_err!(
+                check_runtime_code(&[0; 5]),
+                Error::<Test>::RuntimeCodeTooLarge
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_disallowed_opcode() {
+        new_test_ext().execute_with(|| {
+            set_policy(BytecodePolicy {
+                disallowed_opcodes: vec![SELFDESTRUCT].try_into().unwrap(),
+                ..Default::default()
+            });
+            // CALLER SELFDESTRUCT
+            assert_err!(
+                check_runtime_code(&[0x33, SELFDESTRUCT]),
+                Error::<Test>::DisallowedOpcode
+            );
+        });
+    }
+
+    #[test]
+    fn ignores_disallowed_opcode_in_push_data() {
+        new_test_ext().execute_with(|| {
+            set_policy(BytecodePolicy {
+                disallowed_opcodes: vec![SELFDESTRUCT].try_into().unwrap(),
+                ..Default::default()
+            });
+            // PUSH1 0xff PUSH2 0xffff STOP
+            assert_ok!(check_runtime_code(&[
+                0x60,
+                SELFDESTRUCT,
+                0x61,
+                SELFDESTRUCT,
+                SELFDESTRUCT,
+                0x00
+            ]));
+            // PUSH1 0x00 SELFDESTRUCT
+            assert_err!(
+                check_runtime_code(&[0x60, 0x00, SELFDESTRUCT]),
+                Error::<Test>::DisallowedOpcode
+            );
+        });
+    }
+}
+
+mod pause_contract {
+    use super::*;
+
+    #[test]
+    fn sets_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            assert_ok!(PalletDeployPermissions::pause_contract(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(PausedContracts::<Test>::contains_key(address));
+            System::assert_last_event(Event::ContractPaused { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            assert_noop!(
+                PalletDeployPermissions::pause_contract(
+                    RuntimeOrigin::signed(caller),
+                    H160::repeat_byte(42)
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_already_paused() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            PausedContracts::<Test>::insert(address, ());
+            assert_noop!(
+                PalletDeployPermissions::pause_contract(RuntimeOrigin::root(), address),
+                Error::<Test>::ContractAlreadyPaused
+            );
+        })
+    }
+}
+
+mod unpause_contract {
+    use super::*;
+
+    #[test]
+    fn removes_storage_key_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            PausedContracts::<Test>::insert(address, ());
+            assert_ok!(PalletDeployPermissions::unpause_contract(
+                RuntimeOrigin::root(),
+                address
+            ));
+            assert!(!PausedContracts::<Test>::contains_key(address));
+            System::assert_last_event(Event::ContractUnpaused { address }.into());
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::repeat_byte(42);
+            PausedContracts::<Test>::insert(address, ());
+            assert_noop!(
+                PalletDeployPermissions::unpause_contract(RuntimeOrigin::signed(caller), address),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_not_paused() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::unpause_contract(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42)
+                ),
+                Error::<Test>::ContractNotPaused
+            );
+        })
+    }
+}
+
+mod set_deployer_metadata {
+    use super::*;
+
+    fn label() -> DeployerLabel {
+        b"audited".to_vec().try_into().unwrap()
+    }
+
+    #[test]
+    fn sets_storage_and_emits_event() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let address = H160::repeat_byte(42);
+            let notes_hash = Some(H256::repeat_byte(1));
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(PalletDeployPermissions::set_deployer_metadata(
+                RuntimeOrigin::root(),
+                address,
+                label(),
+                notes_hash
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(address),
+                Some(DeployerInfo {
+                    label: label(),
+                    notes_hash,
+                    ..Default::default()
+                })
+            );
+            System::assert_last_event(
+                Event::DeployerMetadataSet {
+                    address,
+                    label: label(),
+                    notes_hash,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn can_be_invoked_by_manager() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            Managers::<Test>::insert(AccountIdToAddress::convert(caller), ());
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_ok!(PalletDeployPermissions::set_deployer_metadata(
+                RuntimeOrigin::signed(caller),
+                address,
+                label(),
+                None
+            ));
+            assert_eq!(
+                Deployers::<Test>::get(address).map(|info| info.label),
+                Some(label())
+            );
+        });
+    }
+
+    #[test]
+    fn must_be_invoked_by_root() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let caller: <Test as frame_system::Config>::AccountId = 1;
+            let address = H160::repeat_byte(42);
+            Deployers::<Test>::insert(address, DeployerInfo::default());
+            assert_noop!(
+                PalletDeployPermissions::set_deployer_metadata(
+                    RuntimeOrigin::signed(caller),
+                    address,
+                    label(),
+                    None
+                ),
+                DispatchError::BadOrigin
+            );
+        })
+    }
+
+    #[test]
+    fn errors_if_address_does_not_have_deploy_permission() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_noop!(
+                PalletDeployPermissions::set_deployer_metadata(
+                    RuntimeOrigin::root(),
+                    H160::repeat_byte(42),
+                    label(),
+                    None
+                ),
+                Error::<Test>::AddressDoesNotHaveDeployPermission
+            );
+        })
+    }
+}
+
+mod check_call {
+    use super::*;
+
+    #[test]
+    fn returns_ok_for_non_paused_contract() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(<Pallet<Test> as EnsureCreateOrigin<Test>>::check_call(
+                &H160::repeat_byte(42)
+            ));
+        })
+    }
+
+    #[test]
+    fn returns_error_for_paused_contract() {
+        new_test_ext().execute_with(|| {
+            let address = H160::repeat_byte(42);
+            PausedContracts::<Test>::insert(address, ());
+            assert_err!(
+                <Pallet<Test> as EnsureCreateOrigin<Test>>::check_call(&address),
+                Error::<Test>::ContractIsPaused
+            );
+        })
+    }
+}
+
+mod on_idle {
+    use super::*;
+
+    fn insert_deployers(expiries: &[Option<u64>]) -> Vec<H160> {
+        expiries
+            .iter()
+            .enumerate()
+            .map(|(i, expires_at)| {
+                let address = H160::from_low_u64_be(i as u64 + 1);
+                Deployers::<Test>::insert(
+                    address,
+                    DeployerInfo {
+                        expires_at: *expires_at,
+                        ..Default::default()
+                    },
+                );
+                address
+            })
+            .collect()
+    }
+
+    #[test]
+    fn removes_expired_deployers_and_emits_events() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            let addresses = insert_deployers(&[Some(5), None, Some(10), Some(11)]);
+
+            // MaxPrunedPerBlock is 2, so two calls are needed to inspect every deployer.
+            PalletDeployPermissions::on_idle(10, Weight::MAX);
+            PalletDeployPermissions::on_idle(10, Weight::MAX);
+
+            assert!(!Deployers::<Test>::contains_key(addresses[0]));
+            assert!(Deployers::<Test>::contains_key(addresses[1]));
+            assert!(!Deployers::<Test>::contains_key(addresses[2]));
+            assert!(Deployers::<Test>::contains_key(addresses[3]));
+            System::assert_has_event(
+                Event::DeployPermissionExpired {
+                    address: addresses[0],
+                }
+                .into(),
+            );
+            System::assert_has_event(
+                Event::DeployPermissionExpired {
+                    address: addresses[2],
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn inspects_at_most_max_pruned_per_block() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            insert_deployers(&[Some(1), Some(2), Some(3)]);
+
+            let weight = PalletDeployPermissions::on_idle(10, Weight::MAX);
+
+            assert_eq!(weight, <() as WeightInfo>::prune_expired_deployers(2));
+            assert_eq!(Deployers::<Test>::iter_keys().count(), 1);
+            assert!(PruneCursor::<Test>::get().is_some());
+
+            PalletDeployPermissions::on_idle(10, Weight::MAX);
+
+            assert_eq!(Deployers::<Test>::iter_keys().count(), 0);
+            assert!(PruneCursor::<Test>::get().is_none());
+        })
+    }
+
+    #[test]
+    fn does_nothing_without_enough_weight() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(10);
+            insert_deployers(&[Some(1)]);
+
+            let weight = PalletDeployPermissions::on_idle(
+                10,
+                <() as WeightInfo>::prune_expired_deployers(0),
+            );
+
+            assert_eq!(weight, Weight::zero());
+            assert_eq!(Deployers::<Test>::iter_keys().count(), 1);
+        })
+    }
+}
+
+mod migrations {
+    use super::*;
+    use frame_support::storage::unhashed;
+
+    #[test]
+    fn v1_converts_unit_values_to_deployer_info() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::new(0).put::<Pallet<Test>>();
+            let address = H160::repeat_byte(42);
+            unhashed::put_raw(&Deployers::<Test>::hashed_key_for(address), &[]);
+
+            crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+            assert_eq!(
+                crate::migrations::v1::Deployers::<Test>::get(address),
+                Some(crate::migrations::v1::DeployerInfo::default())
+            );
+            assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+        })
+    }
+
+    #[test]
+    fn v2_pauses_provided_contracts() {
+        frame_support::parameter_types! {
+            pub Paused: Vec<H160> = vec![H160::repeat_byte(1), H160::repeat_byte(2)];
+        }
+
+        new_test_ext().execute_with(|| {
+            StorageVersion::new(1).put::<Pallet<Test>>();
+
+            crate::migrations::v2::MigrateV1ToV2::<Test, Paused>::on_runtime_upgrade();
+
+            assert!(Paused::get()
+                .iter()
+                .all(|address| PausedContracts::<Test>::contains_key(address)));
+            assert_eq!(StorageVersion::get::<Pallet<Test>>(), 2);
+        })
+    }
+
+    #[test]
+    fn v3_keeps_expiry_and_defaults_grant_metadata() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::new(2).put::<Pallet<Test>>();
+            let permanent = H160::repeat_byte(1);
+            let temporary = H160::repeat_byte(2);
+            crate::migrations::v1::Deployers::<Test>::insert(
+                permanent,
+                crate::migrations::v1::DeployerInfo { expires_at: None },
+            );
+            crate::migrations::v1::Deployers::<Test>::insert(
+                temporary,
+                crate::migrations::v1::DeployerInfo {
+                    expires_at: Some(10),
+                },
+            );
+
+            crate::migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+            assert_eq!(
+                Deployers::<Test>::get(permanent),
+                Some(DeployerInfo::default())
+            );
+            assert_eq!(
+                Deployers::<Test>::get(temporary),
+                Some(DeployerInfo {
+                    expires_at: Some(10),
+                    granted_by: Granter::Admin,
+                    granted_at: 0,
+                    label: Default::default(),
+                    notes_hash: None,
+                })
             );
+            assert_eq!(StorageVersion::get::<Pallet<Test>>(), 3);
         })
     }
 }
@@ -0,0 +1,252 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompile_helpers::record_weight;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fp_evm::{Context, ExitReason, PrecompileFailure, Transfer};
+use frame_support::{
+    pallet_prelude::MaxEncodedLen,
+    storage::types::{StorageMap, ValueQuery},
+    traits::{ConstU32, StorageInstance, Time},
+    Blake2_128Concat,
+};
+use precompile_utils::{evm::costs::call_cost, prelude::*, solidity};
+use sp_core::{hashing::keccak_256, H160, H256, U256};
+use sp_runtime::traits::{Get, UniqueSaturatedInto};
+
+#[cfg(all(feature = "runtime-benchmarks", feature = "frame-benchmarking"))]
+pub mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Maximum length of the call data a permit can carry.
+pub const CALL_DATA_LIMIT: u32 = 2u32.pow(16);
+
+type CallData = BoundedBytes<ConstU32<CALL_DATA_LIMIT>>;
+
+/// EIP-712 type hash of the permits.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+    "CallPermit(address from,address to,uint256 value,bytes data,uint64 gaslimit,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP-712 type hash of the domain of the permits.
+pub const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Storage prefix of the permit nonces.
+pub struct Nonces;
+
+impl StorageInstance for Nonces {
+    const STORAGE_PREFIX: &'static str = "Nonces";
+
+    fn pallet_prefix() -> &'static str {
+        "PrecompileCallPermit"
+    }
+}
+
+/// Next permit nonce of each account.
+pub type NoncesStorage = StorageMap<Nonces, Blake2_128Concat, H160, U256, ValueQuery>;
+
+/// Dispatches EVM calls on behalf of accounts that signed an EIP-712 permit for them, so that a
+/// relayer can pay for the transaction. Compatible with Moonbeam's call permit precompile.
+pub struct CallPermitPrecompile<R, W>(PhantomData<(R, W)>);
+
+#[precompile_utils::precompile]
+impl<R, W> CallPermitPrecompile<R, W>
+where
+    R: pallet_evm::Config,
+    W: WeightInfo,
+{
+    #[precompile::public(
+        "dispatch(address,address,uint256,bytes,uint64,uint256,uint8,bytes32,bytes32)"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        to: Address,
+        value: U256,
+        data: CallData,
+        gas_limit: u64,
+        deadline: U256,
+        v: u8,
+        r: H256,
+        s: H256,
+    ) -> EvmResult<UnboundedBytes> {
+        let data: Vec<u8> = data.into();
+        record_weight::<R>(handle, W::dispatch(data.len() as u32))?;
+
+        let from: H160 = from.into();
+        let to: H160 = to.into();
+
+        // The subcall must be given the gas the permit was signed for.
+        let call_cost = call_cost(value, <R as pallet_evm::Config>::config());
+        let total_cost = gas_limit
+            .checked_add(call_cost)
+            .ok_or_else(|| revert("call requires too much gas"))?;
+        if total_cost > handle.remaining_gas() {
+            return Err(revert("gas limit is too low to dispatch the call"));
+        }
+
+        Self::use_permit(
+            handle.context().address,
+            from,
+            to,
+            value,
+            &data,
+            gas_limit,
+            deadline,
+            v,
+            r,
+            s,
+        )?;
+
+        let sub_context = Context {
+            caller: from,
+            address: to,
+            apparent_value: value,
+        };
+        let transfer = if value.is_zero() {
+            None
+        } else {
+            Some(Transfer {
+                source: from,
+                target: to,
+                value,
+            })
+        };
+
+        let (reason, output) =
+            handle.call(to, transfer, data, Some(gas_limit), false, &sub_context);
+        match reason {
+            ExitReason::Error(exit_status) => Err(PrecompileFailure::Error { exit_status }),
+            ExitReason::Fatal(exit_status) => Err(PrecompileFailure::Fatal { exit_status }),
+            ExitReason::Revert(exit_status) => Err(PrecompileFailure::Revert {
+                exit_status,
+                output,
+            }),
+            ExitReason::Succeed(_) => Ok(output.into()),
+        }
+    }
+
+    #[precompile::public("nonces(address)")]
+    #[precompile::view]
+    fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        // Nonces: Blake2_128(16) + H160(20) + U256(32)
+        handle.record_db_read::<R>(36 + U256::max_encoded_len())?;
+
+        Ok(NoncesStorage::get(H160::from(owner)))
+    }
+
+    #[precompile::public("DOMAIN_SEPARATOR()")]
+    #[precompile::view]
+    fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+        record_weight::<R>(handle, W::domain_separator())?;
+
+        Ok(Self::compute_domain_separator(handle.context().address).into())
+    }
+}
+
+impl<R, W> CallPermitPrecompile<R, W>
+where
+    R: pallet_evm::Config,
+{
+    fn compute_domain_separator(address: H160) -> [u8; 32] {
+        let name: H256 = keccak_256(b"Call Permit Precompile").into();
+        let version: H256 = keccak256!("1").into();
+        let chain_id: U256 = <R as pallet_evm::Config>::ChainId::get().into();
+
+        keccak_256(&solidity::encode_arguments((
+            H256::from(PERMIT_DOMAIN),
+            name,
+            version,
+            chain_id,
+            Address(address),
+        )))
+    }
+
+    /// The EIP-712 digest the owner of `from` signs to permit the call.
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_permit(
+        address: H160,
+        from: H160,
+        to: H160,
+        value: U256,
+        data: &[u8],
+        gas_limit: u64,
+        nonce: U256,
+        deadline: U256,
+    ) -> [u8; 32] {
+        let permit_content = keccak_256(&solidity::encode_arguments((
+            H256::from(PERMIT_TYPEHASH),
+            Address(from),
+            Address(to),
+            value,
+            H256::from(keccak_256(data)),
+            gas_limit,
+            nonce,
+            deadline,
+        )));
+
+        let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+        pre_digest.extend_from_slice(b"\x19\x01");
+        pre_digest.extend_from_slice(&Self::compute_domain_separator(address));
+        pre_digest.extend_from_slice(&permit_content);
+        keccak_256(&pre_digest)
+    }
+
+    /// Checks the permit signed by `from` and bumps its nonce, so that it can't be used again.
+    #[allow(clippy::too_many_arguments)]
+    fn use_permit(
+        address: H160,
+        from: H160,
+        to: H160,
+        value: U256,
+        data: &[u8],
+        gas_limit: u64,
+        deadline: U256,
+        v: u8,
+        r: H256,
+        s: H256,
+    ) -> EvmResult {
+        // Blockchain time is in ms while Ethereum uses second timestamps.
+        let timestamp: u128 =
+            <R as pallet_evm::Config>::Timestamp::now().unique_saturated_into() / 1000;
+        if deadline < U256::from(timestamp) {
+            return Err(revert("permit expired"));
+        }
+
+        let nonce = NoncesStorage::get(from);
+        let permit =
+            Self::generate_permit(address, from, to, value, data, gas_limit, nonce, deadline);
+
+        let mut sig = [0u8; 65];
+        sig[0..32].copy_from_slice(r.as_bytes());
+        sig[32..64].copy_from_slice(s.as_bytes());
+        sig[64] = v;
+        let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
+            .map_err(|_| revert("invalid permit"))?;
+        if H160::from(H256::from(keccak_256(&signer))) != from {
+            return Err(revert("invalid permit"));
+        }
+
+        NoncesStorage::insert(from, nonce.saturating_add(U256::one()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of the work the call permit precompile does on top of the EVM handle.
+//! Benchmarks are defined on a benchmarking-only pallet, as the precompile has no calls of its
+//! own.
+
+use super::*;
+use fp_account::AccountId20;
+use frame_benchmarking::v2::*;
+use sp_core::crypto::KeyTypeId;
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config: pallet_evm::Config {}
+
+type Precompile<T> = CallPermitPrecompile<T, ()>;
+
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"cprm");
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn dispatch(n: Linear<0, CALL_DATA_LIMIT>) {
+        let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+        let from: H160 = AccountId20::from(public).into();
+        let address = H160::repeat_byte(0x01);
+        let to = H160::repeat_byte(0x02);
+        let data = vec![0x42; n as usize];
+        let deadline = U256::MAX;
+        let permit = Precompile::<T>::generate_permit(
+            address,
+            from,
+            to,
+            U256::zero(),
+            &data,
+            u64::MAX,
+            NoncesStorage::get(from),
+            deadline,
+        );
+        let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &permit)
+            .expect("the key is in the keystore");
+        let r = H256::from_slice(&signature.0[0..32]);
+        let s = H256::from_slice(&signature.0[32..64]);
+        let v = signature.0[64];
+        let used;
+
+        #[block]
+        {
+            used = Precompile::<T>::use_permit(
+                address,
+                from,
+                to,
+                U256::zero(),
+                &data,
+                u64::MAX,
+                deadline,
+                v,
+                r,
+                s,
+            );
+        }
+
+        assert!(used.is_ok());
+        assert_eq!(NoncesStorage::get(from), U256::one());
+    }
+
+    #[benchmark]
+    fn domain_separator() {
+        let separator;
+
+        #[block]
+        {
+            separator = Precompile::<T>::compute_domain_separator(H160::repeat_byte(0x01));
+        }
+
+        assert_ne!(separator, [0u8; 32]);
+    }
+}
@@ -0,0 +1,44 @@
+//! Weights for `call_permit_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet call_permit_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `call_permit_precompile`.
+pub trait WeightInfo {
+    fn dispatch(n: u32, ) -> Weight;
+    fn domain_separator() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `PrecompileCallPermit::Nonces` (r:1 w:1)
+    /// Proof: `PrecompileCallPermit::Nonces` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `EVMChainId::ChainId` (r:1 w:0)
+    /// Proof: `EVMChainId::ChainId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 65536]`.
+    fn dispatch(n: u32, ) -> Weight {
+        Weight::from_parts(46_902_000, 3471)
+            .saturating_add(Weight::from_parts(1_127, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EVMChainId::ChainId` (r:1 w:0)
+    /// Proof: `EVMChainId::ChainId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn domain_separator() -> Weight {
+        Weight::from_parts(4_012_000, 1493)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompile_helpers::dispatch_as_caller;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::MaxEncodedLen,
+    traits::ConstU32,
+    BoundedVec,
+};
+use pallet_collator_selection::{BalanceOf, CandidateInfo};
+use parity_scale_codec::DecodeAll;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+
+/// Maximum length of the SCALE-encoded session keys accepted by the precompile.
+pub const MAX_KEYS_LEN: u32 = 1024;
+/// Maximum length of the ownership proof of the session keys accepted by the precompile.
+pub const MAX_PROOF_LEN: u32 = 1024;
+
+type KeysBytes = BoundedBytes<ConstU32<MAX_KEYS_LEN>>;
+type ProofBytes = BoundedBytes<ConstU32<MAX_PROOF_LEN>>;
+
+/// Exposes collator candidacy of `pallet_collator_selection`, and the session keys it requires,
+/// to EVM tooling. Calls are dispatched with the signed origin of the caller.
+pub struct CollatorStakingPrecompile<R, O, C>(PhantomData<(R, O, C)>);
+
+#[precompile_utils::precompile]
+impl<R, O, C> CollatorStakingPrecompile<R, O, C>
+where
+    R: pallet_collator_selection::Config
+        + pallet_session::Config
+        + pallet_evm::Config<RuntimeOrigin = O>,
+    <R as frame_system::Config>::AccountId: From<H160> + Into<H160>,
+    BalanceOf<R>: TryFrom<U256> + Into<U256>,
+    C: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+        + core::convert::From<pallet_collator_selection::Call<R>>
+        + core::convert::From<pallet_session::Call<R>>,
+    <R as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + From<C>,
+    O: core::convert::From<
+        frame_system::RawOrigin<
+            <<R as pallet_evm::Config>::AccountProvider as fp_evm::AccountProvider>::AccountId,
+        >,
+    >,
+{
+    #[precompile::public("candidates()")]
+    #[precompile::view]
+    fn candidates(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<(Address, U256)>> {
+        // CandidateList: BoundedVec<CandidateInfo, MaxCandidates>
+        handle.record_db_read::<R>(BoundedVec::<
+            CandidateInfo<<R as frame_system::Config>::AccountId, BalanceOf<R>>,
+            <R as pallet_collator_selection::Config>::MaxCandidates,
+        >::max_encoded_len())?;
+
+        Ok(pallet_collator_selection::CandidateList::<R>::get()
+            .into_iter()
+            .map(|candidate| (Address(candidate.who.into()), candidate.deposit.into()))
+            .collect())
+    }
+
+    #[precompile::public("invulnerables()")]
+    #[precompile::view]
+    fn invulnerables(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        // Invulnerables: BoundedVec<AccountId, MaxInvulnerables>
+        handle.record_db_read::<R>(BoundedVec::<
+            <R as frame_system::Config>::AccountId,
+            <R as pallet_collator_selection::Config>::MaxInvulnerables,
+        >::max_encoded_len())?;
+
+        Ok(pallet_collator_selection::Invulnerables::<R>::get()
+            .into_iter()
+            .map(|account| Address(account.into()))
+            .collect())
+    }
+
+    #[precompile::public("candidacyBond()")]
+    #[precompile::view]
+    fn candidacy_bond(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // CandidacyBond: Balance
+        handle.record_db_read::<R>(BalanceOf::<R>::max_encoded_len())?;
+
+        Ok(pallet_collator_selection::CandidacyBond::<R>::get().into())
+    }
+
+    #[precompile::public("registerAsCandidate()")]
+    fn register_as_candidate(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let call = pallet_collator_selection::Call::<R>::register_as_candidate {};
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("leaveIntent()")]
+    fn leave_intent(handle: &mut impl PrecompileHandle) -> EvmResult {
+        let call = pallet_collator_selection::Call::<R>::leave_intent {};
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("updateBond(uint256)")]
+    fn update_bond(handle: &mut impl PrecompileHandle, new_deposit: U256) -> EvmResult {
+        let call = pallet_collator_selection::Call::<R>::update_bond {
+            new_deposit: Self::balance(new_deposit, "newDeposit")?,
+        };
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("takeCandidateSlot(uint256,address)")]
+    fn take_candidate_slot(
+        handle: &mut impl PrecompileHandle,
+        deposit: U256,
+        target: Address,
+    ) -> EvmResult {
+        let call = pallet_collator_selection::Call::<R>::take_candidate_slot {
+            deposit: Self::balance(deposit, "deposit")?,
+            target: H160::from(target).into(),
+        };
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("setKeys(bytes,bytes)")]
+    fn set_keys(
+        handle: &mut impl PrecompileHandle,
+        keys: KeysBytes,
+        proof: ProofBytes,
+    ) -> EvmResult {
+        let keys = <R as pallet_session::Config>::Keys::decode_all(&mut keys.as_bytes())
+            .map_err(|_| RevertReason::custom("cannot decode session keys").in_field("keys"))?;
+        let call = pallet_session::Call::<R>::set_keys {
+            keys,
+            proof: proof.into(),
+        };
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    fn balance(value: U256, field: &'static str) -> EvmResult<BalanceOf<R>> {
+        value.try_into().map_err(|_| {
+            RevertReason::value_is_too_large("balance type")
+                .in_field(field)
+                .into()
+        })
+    }
+}
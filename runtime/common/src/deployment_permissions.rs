@@ -0,0 +1,117 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompile_helpers::dispatch_as_caller;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::MaxEncodedLen,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_deployment_permissions::{DeployerInfo, DeploymentMode};
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+
+/// Solidity selector of the `DeployPermissionGranted` log. Its data is the block from which the
+/// permission is no longer valid, or zero if it never expires.
+pub const SELECTOR_LOG_DEPLOY_PERMISSION_GRANTED: [u8; 32] =
+    keccak256!("DeployPermissionGranted(address,address,uint256)");
+
+/// Solidity selector of the `DeployPermissionRevoked` log.
+pub const SELECTOR_LOG_DEPLOY_PERMISSION_REVOKED: [u8; 32] =
+    keccak256!("DeployPermissionRevoked(address,address)");
+
+/// Exposes `pallet_deployment_permissions` to EVM contracts and tooling. Grants and revokes are
+/// dispatched with the signed origin of the caller, which the pallet checks is a manager.
+pub struct DeploymentPermissionsPrecompile<R, O, C>(PhantomData<(R, O, C)>);
+
+#[precompile_utils::precompile]
+impl<R, O, C> DeploymentPermissionsPrecompile<R, O, C>
+where
+    R: pallet_deployment_permissions::Config + pallet_evm::Config<RuntimeOrigin = O>,
+    C: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+        + core::convert::From<pallet_deployment_permissions::Call<R>>,
+    <R as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + From<C>,
+    O: core::convert::From<
+        frame_system::RawOrigin<
+            <<R as pallet_evm::Config>::AccountProvider as fp_evm::AccountProvider>::AccountId,
+        >,
+    >,
+{
+    #[precompile::public("isDeployer(address)")]
+    #[precompile::view]
+    fn is_deployer(handle: &mut impl PrecompileHandle, address: Address) -> EvmResult<bool> {
+        // Deployers: Blake2_128(16) + H160(20) + DeployerInfo
+        handle.record_db_read::<R>(36 + DeployerInfo::<BlockNumberFor<R>>::max_encoded_len())?;
+        // Number: BlockNumber
+        handle.record_db_read::<R>(BlockNumberFor::<R>::max_encoded_len())?;
+
+        Ok(pallet_deployment_permissions::Pallet::<R>::is_deployer(
+            &address.into(),
+        ))
+    }
+
+    #[precompile::public("deploymentMode()")]
+    #[precompile::view]
+    fn deployment_mode(handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+        // Mode: DeploymentMode
+        handle.record_db_read::<R>(DeploymentMode::max_encoded_len())?;
+
+        Ok(pallet_deployment_permissions::Mode::<R>::get() as u8)
+    }
+
+    #[precompile::public("grant(address)")]
+    fn grant(handle: &mut impl PrecompileHandle, address: Address) -> EvmResult {
+        handle.record_log_costs_manual(3, 32)?;
+
+        let address: H160 = address.into();
+        let call = pallet_deployment_permissions::Call::<R>::grant_deploy_permission { address };
+        dispatch_as_caller::<R, O, C>(handle, call.into())?;
+
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_DEPLOY_PERMISSION_GRANTED,
+            address,
+            handle.context().caller,
+            // Permissions granted with `grant` never expire.
+            precompile_utils::solidity::encode_event_data(U256::zero()),
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("revoke(address)")]
+    fn revoke(handle: &mut impl PrecompileHandle, address: Address) -> EvmResult {
+        handle.record_log_costs_manual(3, 0)?;
+
+        let address: H160 = address.into();
+        let call = pallet_deployment_permissions::Call::<R>::revoke_deploy_permission { address };
+        dispatch_as_caller::<R, O, C>(handle, call.into())?;
+
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_DEPLOY_PERMISSION_REVOKED,
+            address,
+            handle.context().caller,
+            vec![],
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+}
@@ -3,10 +3,15 @@
 #[macro_use]
 extern crate alloc;
 
+pub mod call_permit;
+pub mod collator_staking;
 pub mod constants;
 pub use constants::*;
+pub mod deployment_permissions;
+pub mod precompile_helpers;
 pub mod types;
 pub mod xcm_teleport;
+pub mod xcm_utils;
 pub use types::{
     AccountId, Address, AssetId, Balance, BlockNumber, Hash, Header, Nonce, Signature,
 };
@@ -0,0 +1,65 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers shared by the precompiles of this crate.
+//!
+//! Precompiles charging benchmarked weights keep everything they do besides touching the EVM
+//! handle in a separate `impl` block, so that it can be benchmarked without an EVM.
+
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    weights::Weight,
+};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use precompile_utils::prelude::*;
+use sp_runtime::traits::Dispatchable;
+
+/// Charges `weight` to the EVM: its ref time converted to gas through `GasWeightMapping`
+/// and its proof size against the PoV limit of the transaction.
+pub fn record_weight<R: pallet_evm::Config>(
+    handle: &mut impl PrecompileHandle,
+    weight: Weight,
+) -> EvmResult {
+    handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()), None)?;
+    handle.record_cost(<R as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+        weight,
+    ))?;
+
+    Ok(())
+}
+
+/// Dispatches `call` with the caller as signed origin: its weight is charged as gas by
+/// `try_dispatch`.
+pub fn dispatch_as_caller<R, O, C>(handle: &mut impl PrecompileHandle, call: C) -> EvmResult
+where
+    R: pallet_evm::Config<RuntimeOrigin = O>,
+    C: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    <R as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + From<C>,
+    O: core::convert::From<
+        frame_system::RawOrigin<
+            <<R as pallet_evm::Config>::AccountProvider as fp_evm::AccountProvider>::AccountId,
+        >,
+    >,
+{
+    // We use IdentityAddressMapping, so no db access
+    let account_id =
+        <R as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
+    let origin: O = frame_system::RawOrigin::Signed(account_id).into();
+
+    RuntimeHelper::<R>::try_dispatch::<C>(handle, origin, call, 0)?;
+
+    Ok(())
+}
@@ -13,38 +13,58 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::alloc::borrow::ToOwned;
+use crate::precompile_helpers::{dispatch_as_caller, record_weight};
 use alloc::boxed::Box;
 use core::marker::PhantomData;
-use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
-use pallet_evm::AddressMapping;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    traits::{ConstU32, Contains},
+    weights::Weight,
+};
+use parity_scale_codec::Encode;
 use precompile_utils::prelude::*;
-use sp_core::{H256, U256};
+use sp_core::{hashing::blake2_256, H160, H256, U256};
 use sp_runtime::traits::{Dispatchable, Get};
 use xcm::{
     v5::{
         Asset,
         AssetFilter::Wild,
         AssetId, Assets, Fungibility,
-        Instruction::{BuyExecution, ClearOrigin, DepositAsset, ReceiveTeleportedAsset},
+        Instruction::{
+            BuyExecution, ClearOrigin, DepositAsset, InitiateTeleport, ReceiveTeleportedAsset,
+            SetFeesMode, SetTopic, WithdrawAsset,
+        },
         Junction, Location, Reanchorable, WeightLimit,
         WildAsset::AllCounted,
-        Xcm,
+        Xcm, XcmHash,
     },
-    VersionedAssets, VersionedLocation, VersionedXcm,
+    VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm,
 };
+use xcm_executor::traits::TransferType;
+
+#[cfg(all(feature = "runtime-benchmarks", feature = "frame-benchmarking"))]
+pub mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
 
-pub struct XcmTeleportPrecompile<R, O, C, L, A>(PhantomData<(R, O, C, L, A)>);
+/// Solidity selector of the `Teleported` log.
+pub const SELECTOR_LOG_TELEPORTED: [u8; 32] =
+    keccak256!("Teleported(address,bytes32,uint256,bytes32)");
+
+/// Teleports the native asset `A` to the relay chain `L` or to the sibling parachains in `P`.
+pub struct XcmTeleportPrecompile<R, O, C, L, A, P, W>(PhantomData<(R, O, C, L, A, P, W)>);
 
 struct TeleportCallParams {
     destination: Location,
     beneficiary: Location,
     assets: Assets,
-    fee_asset_item: u32,
+    fees: Asset,
+    weight_limit: WeightLimit,
 }
 
 #[precompile_utils::precompile]
-impl<R, O, C, L, A> XcmTeleportPrecompile<R, O, C, L, A>
+impl<R, O, C, L, A, P, W> XcmTeleportPrecompile<R, O, C, L, A, P, W>
 where
     R: pallet_xcm::Config + pallet_evm::Config<RuntimeOrigin = O>,
     C: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
@@ -58,40 +78,91 @@ where
     >,
     L: Get<Location>,
     A: Get<AssetId>,
+    P: Contains<u32>,
+    W: WeightInfo,
 {
     #[precompile::public("teleportToRelayChain(bytes32,uint256)")]
     fn teleport_to_relay_chain(
         handle: &mut impl PrecompileHandle,
         destination_account: H256,
         amount: U256,
-    ) -> EvmResult {
-        // No benchmarks availabe yet for precompiles, so charge some arbitrary gas as a spam
-        // prevention mechanism.
-        handle.record_cost(1000)?;
+    ) -> EvmResult<H256> {
+        // The transfer dispatch is charged on its own by `try_dispatch`.
+        record_weight::<R>(handle, W::teleport_to_relay_chain())?;
+        handle.record_log_costs_manual(3, 64)?;
 
-        // We use IdentityAddressMapping, so no db access
-        let account_id =
-            <R as pallet_evm::Config>::AddressMapping::into_account_id(handle.context().caller);
-        let origin: O = frame_system::RawOrigin::Signed(account_id).into();
+        let message_id = Self::message_topic(handle.context().caller);
+        let call = Self::teleport_call(
+            L::get(),
+            Self::relay_beneficiary(destination_account),
+            amount,
+            message_id,
+        )?;
+        dispatch_as_caller::<R, O, C>(handle, call.into())?;
 
-        let TeleportCallParams {
-            destination,
-            beneficiary,
-            assets,
-            fee_asset_item,
-        } = Self::build_teleport_params(destination_account, amount)?;
+        Self::log_teleported(handle, destination_account, amount, message_id)?;
 
-        let call: C = pallet_xcm::Call::<R>::teleport_assets {
-            dest: Box::new(VersionedLocation::V5(destination)),
-            beneficiary: Box::new(VersionedLocation::V5(beneficiary)),
-            assets: Box::new(VersionedAssets::V5(assets)),
-            fee_asset_item,
-        }
-        .into();
+        Ok(message_id.into())
+    }
+
+    #[precompile::public("teleportToRelayChainWithLimits(bytes32,uint256,uint256,uint64)")]
+    fn teleport_to_relay_chain_with_limits(
+        handle: &mut impl PrecompileHandle,
+        destination_account: H256,
+        amount: U256,
+        max_fee: U256,
+        weight_limit: u64,
+    ) -> EvmResult<H256> {
+        // The `execute` dispatch is charged on its own by `try_dispatch`.
+        record_weight::<R>(handle, W::teleport_to_relay_chain_with_limits())?;
+        handle.record_log_costs_manual(3, 64)?;
+
+        let message_id = Self::message_topic(handle.context().caller);
+        let call = Self::limited_teleport_call(
+            Self::build_limited_teleport_params(
+                L::get(),
+                Self::relay_beneficiary(destination_account),
+                amount,
+                max_fee,
+                weight_limit,
+            )?,
+            message_id,
+        )?;
+        dispatch_as_caller::<R, O, C>(handle, call.into())?;
 
-        RuntimeHelper::<R>::try_dispatch::<C>(handle, origin, call, 0)?;
+        Self::log_teleported(handle, destination_account, amount, message_id)?;
 
-        Ok(())
+        Ok(message_id.into())
+    }
+
+    #[precompile::public("teleportToParachain(uint32,bytes,uint256)")]
+    fn teleport_to_parachain(
+        handle: &mut impl PrecompileHandle,
+        para_id: u32,
+        beneficiary: BoundedBytes<ConstU32<32>>,
+        amount: U256,
+    ) -> EvmResult<H256> {
+        // The transfer dispatch is charged on its own by `try_dispatch`.
+        record_weight::<R>(handle, W::teleport_to_parachain())?;
+        handle.record_log_costs_manual(3, 64)?;
+
+        let message_id = Self::message_topic(handle.context().caller);
+        let call = Self::teleport_call(
+            Self::parachain_destination(para_id)?,
+            Self::parachain_beneficiary(beneficiary.as_bytes())?,
+            amount,
+            message_id,
+        )?;
+        dispatch_as_caller::<R, O, C>(handle, call.into())?;
+
+        Self::log_teleported(
+            handle,
+            Self::beneficiary_topic(beneficiary.as_bytes()),
+            amount,
+            message_id,
+        )?;
+
+        Ok(message_id.into())
     }
 
     #[precompile::public("deliveryFee(bytes32,uint256)")]
@@ -100,28 +171,173 @@ where
         destination_account: H256,
         amount: U256,
     ) -> EvmResult<U256> {
-        // No benchmarks availabe yet for precompiles, so charge some arbitrary gas as a spam
-        // prevention mechanism.
-        handle.record_cost(1000)?;
+        record_weight::<R>(handle, W::delivery_fee())?;
+
+        Self::query_teleport_delivery_fee(
+            Self::build_teleport_params(
+                L::get(),
+                Self::relay_beneficiary(destination_account),
+                amount,
+            )?,
+            Self::message_topic(handle.context().caller),
+        )
+    }
 
+    #[precompile::public("deliveryFee(bytes32,uint256,uint256,uint64)")]
+    fn delivery_fee_with_limits(
+        handle: &mut impl PrecompileHandle,
+        destination_account: H256,
+        amount: U256,
+        max_fee: U256,
+        weight_limit: u64,
+    ) -> EvmResult<U256> {
+        // Quoting a limited teleport costs the same as quoting an unlimited one.
+        record_weight::<R>(handle, W::delivery_fee())?;
+
+        Self::query_teleport_delivery_fee(
+            Self::build_limited_teleport_params(
+                L::get(),
+                Self::relay_beneficiary(destination_account),
+                amount,
+                max_fee,
+                weight_limit,
+            )?,
+            Self::message_topic(handle.context().caller),
+        )
+    }
+
+    #[precompile::public("deliveryFee(uint32,bytes,uint256)")]
+    fn delivery_fee_to_parachain(
+        handle: &mut impl PrecompileHandle,
+        para_id: u32,
+        beneficiary: BoundedBytes<ConstU32<32>>,
+        amount: U256,
+    ) -> EvmResult<U256> {
+        record_weight::<R>(handle, W::delivery_fee_to_parachain())?;
+
+        Self::query_teleport_delivery_fee(
+            Self::build_teleport_params(
+                Self::parachain_destination(para_id)?,
+                Self::parachain_beneficiary(beneficiary.as_bytes())?,
+                amount,
+            )?,
+            Self::message_topic(handle.context().caller),
+        )
+    }
+
+    fn log_teleported(
+        handle: &mut impl PrecompileHandle,
+        beneficiary: H256,
+        amount: U256,
+        message_id: XcmHash,
+    ) -> EvmResult {
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_TELEPORTED,
+            handle.context().caller,
+            beneficiary,
+            precompile_utils::solidity::encode_event_data((amount, H256::from(message_id))),
+        )
+        .record(handle)
+    }
+}
+
+impl<R, O, C, L, A, P, W> XcmTeleportPrecompile<R, O, C, L, A, P, W>
+where
+    R: pallet_xcm::Config,
+    A: Get<AssetId>,
+    P: Contains<u32>,
+{
+    /// Builds the same transfer as `teleport_assets`, but ending the message sent to
+    /// `destination` with `SetTopic(message_id)`: routers keep a trailing topic as the message
+    /// id, so the caller can track the message across chains.
+    fn teleport_call(
+        destination: Location,
+        beneficiary: Location,
+        amount: U256,
+        message_id: XcmHash,
+    ) -> EvmResult<pallet_xcm::Call<R>> {
         let TeleportCallParams {
             destination,
             beneficiary,
             assets,
-            fee_asset_item,
-        } = Self::build_teleport_params(destination_account, amount)?;
+            fees: _,
+            weight_limit,
+        } = Self::build_teleport_params(destination, beneficiary, amount)?;
+        let max_assets = assets.len() as u32;
 
-        let program = Self::teleport_assets_program(
-            destination.clone(),
-            beneficiary,
-            assets,
-            fee_asset_item,
-        )?;
+        Ok(pallet_xcm::Call::<R>::transfer_assets_using_type_and_then {
+            dest: Box::new(VersionedLocation::V5(destination)),
+            assets: Box::new(VersionedAssets::V5(assets)),
+            assets_transfer_type: Box::new(TransferType::Teleport),
+            remote_fees_id: Box::new(VersionedAssetId::V5(A::get())),
+            fees_transfer_type: Box::new(TransferType::Teleport),
+            custom_xcm_on_dest: Box::new(VersionedXcm::V5(Xcm(vec![
+                DepositAsset {
+                    assets: Wild(AllCounted(max_assets)),
+                    beneficiary,
+                },
+                SetTopic(message_id),
+            ]))),
+            weight_limit,
+        })
+    }
+
+    /// Executes locally the program `teleport_assets` would, but for the remote fees and
+    /// weight limit of `params`.
+    fn limited_teleport_call(
+        params: TeleportCallParams,
+        message_id: XcmHash,
+    ) -> EvmResult<pallet_xcm::Call<R>> {
+        let program = Self::limited_teleport_program(params, message_id)?;
+        let max_weight =
+            pallet_xcm::Pallet::<R>::query_xcm_weight(VersionedXcm::V5(program.clone()))
+                .map_err(|_| revert("cannot weigh teleport"))?;
+
+        Ok(pallet_xcm::Call::<R>::execute {
+            message: Box::new(VersionedXcm::V5(program.into())),
+            max_weight,
+        })
+    }
+
+    /// A topic unique to this teleport: the events deposited by a teleport make the event count
+    /// differ for every teleport in the same block.
+    fn message_topic(caller: H160) -> XcmHash {
+        (
+            frame_system::Pallet::<R>::block_number(),
+            frame_system::Pallet::<R>::event_count(),
+            caller,
+        )
+            .using_encoded(blake2_256)
+    }
+
+    /// The beneficiary as log topic: 20 bytes accounts are left-padded like addresses.
+    fn beneficiary_topic(account: &[u8]) -> H256 {
+        match <[u8; 20]>::try_from(account) {
+            Ok(key) => H160(key).into(),
+            Err(_) => H256::from_slice(account),
+        }
+    }
+
+    /// Quotes the delivery fee of the teleport described by `params`, on the program the
+    /// destination receives when the teleport is tagged with `message_id`.
+    fn query_teleport_delivery_fee(
+        params: TeleportCallParams,
+        message_id: XcmHash,
+    ) -> EvmResult<U256> {
+        let destination = params.destination.clone();
+
+        Self::query_delivery_fee(
+            destination,
+            Self::teleport_sent_program(params, message_id)?,
+        )
+    }
 
+    fn query_delivery_fee(destination: Location, program: Xcm<()>) -> EvmResult<U256> {
         let versioned_fees = pallet_xcm::Pallet::<R>::query_delivery_fees::<()>(
             VersionedLocation::V5(destination),
             VersionedXcm::V5(program),
-            xcm::VersionedAssetId::V5(A::get()),
+            VersionedAssetId::V5(A::get()),
         )
         .map_err(|_| revert("cannot query delivery fees"))?;
 
@@ -129,7 +345,7 @@ where
             .try_into()
             .map_err(|_| RevertReason::custom("xcm conversion error"))?;
         match fees
-            .get(fee_asset_item as usize)
+            .get(0)
             .ok_or_else(|| RevertReason::read_out_of_bounds("fees"))?
             .fun
         {
@@ -140,23 +356,27 @@ where
         }
     }
 
-    fn teleport_assets_program(
-        dest: Location,
-        beneficiary: Location,
-        assets: Assets,
-        fee_asset_item: u32,
+    /// The program the destination receives for the teleport described by `params`, tagged with
+    /// `message_id`: the one sent by [`Self::teleport_call`] and, prepending the received assets
+    /// to its inner program, by the `InitiateTeleport` of [`Self::limited_teleport_program`].
+    fn teleport_sent_program(
+        params: TeleportCallParams,
+        message_id: XcmHash,
     ) -> EvmResult<Xcm<()>> {
-        let fees = assets
-            .get(fee_asset_item as usize)
-            .ok_or_else(|| RevertReason::read_out_of_bounds("fees"))?
-            .to_owned();
+        let TeleportCallParams {
+            destination,
+            beneficiary,
+            assets,
+            fees,
+            weight_limit,
+        } = params;
         let max_assets = assets.len() as u32;
         let context = R::UniversalLocation::get();
         let reanchored_assets = assets
-            .reanchored(&dest, &context)
+            .reanchored(&destination, &context)
             .map_err(|_| revert("cannot reanchor assets"))?;
         let reanchored_fees = fees
-            .reanchored(&dest, &context)
+            .reanchored(&destination, &context)
             .map_err(|_| revert("cannot reanchor fees"))?;
 
         Ok(Xcm(vec![
@@ -164,45 +384,136 @@ where
             ClearOrigin,
             BuyExecution {
                 fees: reanchored_fees,
-                weight_limit: WeightLimit::Unlimited,
+                weight_limit,
             },
             DepositAsset {
                 assets: Wild(AllCounted(max_assets)),
                 beneficiary,
             },
+            SetTopic(message_id),
         ]))
     }
 
-    fn build_teleport_params(
-        destination_account: H256,
-        amount: U256,
-    ) -> EvmResult<TeleportCallParams> {
-        let destination = L::get();
+    /// The local program sending the teleport described by `params`, tagged with `message_id`.
+    /// Delivery fees are withdrawn from the caller rather than from the teleported assets.
+    fn limited_teleport_program(
+        params: TeleportCallParams,
+        message_id: XcmHash,
+    ) -> EvmResult<Xcm<()>> {
+        let TeleportCallParams {
+            destination,
+            beneficiary,
+            assets,
+            fees,
+            weight_limit,
+        } = params;
+        let max_assets = assets.len() as u32;
+        let reanchored_fees = fees
+            .reanchored(&destination, &R::UniversalLocation::get())
+            .map_err(|_| revert("cannot reanchor fees"))?;
 
-        let beneficiary = Location::new(
+        Ok(Xcm(vec![
+            WithdrawAsset(assets),
+            SetFeesMode { jit_withdraw: true },
+            InitiateTeleport {
+                assets: Wild(AllCounted(max_assets)),
+                dest: destination,
+                xcm: Xcm(vec![
+                    BuyExecution {
+                        fees: reanchored_fees,
+                        weight_limit,
+                    },
+                    DepositAsset {
+                        assets: Wild(AllCounted(max_assets)),
+                        beneficiary,
+                    },
+                    SetTopic(message_id),
+                ]),
+            },
+        ]))
+    }
+
+    fn parachain_destination(para_id: u32) -> EvmResult<Location> {
+        if !P::contains(&para_id) {
+            return Err(revert("cannot teleport to parachain"));
+        }
+
+        Ok(Location::new(1, [Junction::Parachain(para_id)]))
+    }
+
+    fn relay_beneficiary(destination_account: H256) -> Location {
+        Location::new(
             0,
             [Junction::AccountId32 {
                 network: None,
                 id: destination_account.into(),
             }],
-        );
+        )
+    }
+
+    /// Parachains may have either 32 or 20 bytes accounts.
+    fn parachain_beneficiary(account: &[u8]) -> EvmResult<Location> {
+        let junction = if let Ok(id) = account.try_into() {
+            Junction::AccountId32 { network: None, id }
+        } else if let Ok(key) = account.try_into() {
+            Junction::AccountKey20 { network: None, key }
+        } else {
+            Err(RevertReason::custom("beneficiary must be 20 or 32 bytes"))?
+        };
+
+        Ok(Location::new(0, [junction]))
+    }
 
+    fn build_teleport_params(
+        destination: Location,
+        beneficiary: Location,
+        amount: U256,
+    ) -> EvmResult<TeleportCallParams> {
         let amount_u128 = amount
             .try_into()
             .map_err(|_| RevertReason::value_is_too_large("amount"))?;
 
-        let assets = Assets::from(vec![Asset {
+        let fees = Asset {
             id: A::get(),
             fun: Fungibility::Fungible(amount_u128),
-        }]);
-
-        let fee_asset_item = 0;
+        };
+        let assets = Assets::from(vec![fees.clone()]);
 
         Ok(TeleportCallParams {
             destination,
             beneficiary,
             assets,
-            fee_asset_item,
+            fees,
+            weight_limit: WeightLimit::Unlimited,
+        })
+    }
+
+    /// Pays the remote execution with at most `max_fee` of the teleported `amount`, for at most
+    /// `weight_limit` of ref time and the proof size of a whole relay chain PoV.
+    fn build_limited_teleport_params(
+        destination: Location,
+        beneficiary: Location,
+        amount: U256,
+        max_fee: U256,
+        weight_limit: u64,
+    ) -> EvmResult<TeleportCallParams> {
+        if max_fee > amount {
+            return Err(revert("max fee exceeds amount"));
+        }
+        let max_fee_u128 = max_fee
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("max fee"))?;
+
+        Ok(TeleportCallParams {
+            fees: Asset {
+                id: A::get(),
+                fun: Fungibility::Fungible(max_fee_u128),
+            },
+            weight_limit: WeightLimit::Limited(Weight::from_parts(
+                weight_limit,
+                cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64,
+            )),
+            ..Self::build_teleport_params(destination, beneficiary, amount)?
         })
     }
 }
@@ -0,0 +1,183 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of the work the XCM teleport precompile does on top of the EVM handle.
+//! Benchmarks are defined on a benchmarking-only pallet, as the precompile has no storage nor
+//! calls of its own.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use sp_core::H160;
+use xcm_builder::EnsureDelivery;
+use xcm_executor::traits::FeeReason;
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config: pallet_xcm::benchmarking::Config + pallet_evm::Config {
+    /// Relay chain the precompile teleports to.
+    type Destination: Get<Location>;
+    /// Teleported asset, as configured in the precompile.
+    type Asset: Get<AssetId>;
+    /// Sibling parachains the precompile can teleport to.
+    type Parachains: Contains<u32>;
+    /// Ensures the delivery of messages to sibling parachains.
+    type SiblingDeliveryHelper: EnsureDelivery;
+
+    /// A parachain in `Parachains`.
+    fn parachain() -> u32;
+}
+
+type Precompile<T> = XcmTeleportPrecompile<
+    T,
+    (),
+    (),
+    <T as Config>::Destination,
+    <T as Config>::Asset,
+    <T as Config>::Parachains,
+    (),
+>;
+
+fn caller_location() -> Location {
+    let caller: H160 = whitelisted_caller();
+    Location::new(
+        0,
+        [Junction::AccountKey20 {
+            network: None,
+            key: caller.into(),
+        }],
+    )
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn teleport_to_relay_chain() {
+        let call;
+
+        #[block]
+        {
+            call = Precompile::<T>::teleport_call(
+                T::Destination::get(),
+                Precompile::<T>::relay_beneficiary(H256::repeat_byte(0x42)),
+                U256::from(u128::MAX),
+                Precompile::<T>::message_topic(whitelisted_caller()),
+            );
+        }
+
+        assert!(call.is_ok());
+    }
+
+    #[benchmark]
+    fn teleport_to_parachain() {
+        let para_id = T::parachain();
+        let call;
+
+        #[block]
+        {
+            // A 20 bytes account is the last one being tried.
+            call = Precompile::<T>::parachain_destination(para_id).and_then(|destination| {
+                Precompile::<T>::teleport_call(
+                    destination,
+                    Precompile::<T>::parachain_beneficiary(&[0x42; 20])?,
+                    U256::from(u128::MAX),
+                    Precompile::<T>::message_topic(whitelisted_caller()),
+                )
+            });
+        }
+
+        assert!(call.is_ok());
+    }
+
+    #[benchmark]
+    fn teleport_to_relay_chain_with_limits() {
+        let call;
+
+        #[block]
+        {
+            call = Precompile::<T>::build_limited_teleport_params(
+                T::Destination::get(),
+                Precompile::<T>::relay_beneficiary(H256::repeat_byte(0x42)),
+                U256::from(u128::MAX),
+                U256::from(u128::MAX),
+                u64::MAX,
+            )
+            .and_then(|params| {
+                Precompile::<T>::limited_teleport_call(
+                    params,
+                    Precompile::<T>::message_topic(whitelisted_caller()),
+                )
+            });
+        }
+
+        assert!(call.is_ok());
+    }
+
+    #[benchmark]
+    fn delivery_fee() {
+        T::DeliveryHelper::ensure_successful_delivery(
+            &caller_location(),
+            &T::Destination::get(),
+            FeeReason::ChargeFees,
+        );
+        let fee;
+
+        #[block]
+        {
+            fee = Precompile::<T>::build_teleport_params(
+                T::Destination::get(),
+                Precompile::<T>::relay_beneficiary(H256::repeat_byte(0x42)),
+                U256::from(u128::MAX),
+            )
+            .and_then(|params| {
+                Precompile::<T>::query_teleport_delivery_fee(
+                    params,
+                    Precompile::<T>::message_topic(whitelisted_caller()),
+                )
+            });
+        }
+
+        assert!(fee.is_ok());
+    }
+
+    #[benchmark]
+    fn delivery_fee_to_parachain() {
+        let para_id = T::parachain();
+        let destination = Location::new(1, [Junction::Parachain(para_id)]);
+        T::SiblingDeliveryHelper::ensure_successful_delivery(
+            &caller_location(),
+            &destination,
+            FeeReason::ChargeFees,
+        );
+        let fee;
+
+        #[block]
+        {
+            fee = Precompile::<T>::parachain_destination(para_id).and_then(|destination| {
+                Precompile::<T>::query_teleport_delivery_fee(
+                    Precompile::<T>::build_teleport_params(
+                        destination,
+                        Precompile::<T>::parachain_beneficiary(&[0x42; 20])?,
+                        U256::from(u128::MAX),
+                    )?,
+                    Precompile::<T>::message_topic(whitelisted_caller()),
+                )
+            });
+        }
+
+        assert!(fee.is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+//! Weights for `xcm_teleport_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet xcm_teleport_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `xcm_teleport_precompile`.
+pub trait WeightInfo {
+    fn teleport_to_relay_chain() -> Weight;
+    fn teleport_to_parachain() -> Weight;
+    fn teleport_to_relay_chain_with_limits() -> Weight;
+    fn delivery_fee() -> Weight;
+    fn delivery_fee_to_parachain() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn teleport_to_relay_chain() -> Weight {
+        Weight::from_parts(3_105_000, 0)
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn teleport_to_parachain() -> Weight {
+        Weight::from_parts(5_390_000, 1489)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    fn teleport_to_relay_chain_with_limits() -> Weight {
+        Weight::from_parts(6_981_000, 0)
+    }
+    /// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+    /// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ZKVXcm::SupportedVersion` (r:1 w:0)
+    /// Proof: `ZKVXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn delivery_fee() -> Weight {
+        Weight::from_parts(14_503_000, 3540)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+    /// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `ZKVXcm::SupportedVersion` (r:1 w:0)
+    /// Proof: `ZKVXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+    /// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn delivery_fee_to_parachain() -> Weight {
+        Weight::from_parts(19_117_000, 3603)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::precompile_helpers::{dispatch_as_caller, record_weight};
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    traits::ConstU32,
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFee},
+};
+use pallet_transaction_payment::BalanceOf;
+use parity_scale_codec::DecodeLimit;
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::{Dispatchable, Get};
+use xcm::{
+    v5::{AssetId, Location},
+    VersionedLocation, VersionedXcm, MAX_XCM_DECODE_DEPTH,
+};
+
+#[cfg(all(feature = "runtime-benchmarks", feature = "frame-benchmarking"))]
+pub mod benchmarking;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Maximum length of the SCALE-encoded messages accepted by the precompile.
+pub const MAX_MESSAGE_LEN: u32 = 64 * 1024;
+/// Maximum length of the SCALE-encoded locations accepted by the precompile.
+pub const MAX_LOCATION_LEN: u32 = 1024;
+
+type MessageBytes = BoundedBytes<ConstU32<MAX_MESSAGE_LEN>>;
+type LocationBytes = BoundedBytes<ConstU32<MAX_LOCATION_LEN>>;
+
+/// Sends, executes and weighs arbitrary XCM messages, given as SCALE-encoded `VersionedXcm`.
+/// Messages are sent and executed through `pallet_xcm` with the signed origin of the caller, so
+/// `SendXcmOrigin`, `ExecuteXcmOrigin` and `XcmExecuteFilter` apply as for any other account.
+/// XCM fees are paid in the native asset `A`.
+pub struct XcmUtilsPrecompile<R, O, C, A, W>(PhantomData<(R, O, C, A, W)>);
+
+#[precompile_utils::precompile]
+impl<R, O, C, A, W> XcmUtilsPrecompile<R, O, C, A, W>
+where
+    R: pallet_xcm::Config
+        + pallet_evm::Config<RuntimeOrigin = O>
+        + pallet_transaction_payment::Config,
+    BalanceOf<R>: Into<U256>,
+    C: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
+        + core::convert::From<pallet_xcm::Call<R>>,
+    <R as frame_system::Config>::RuntimeCall:
+        Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + From<C>,
+    O: core::convert::From<
+        frame_system::RawOrigin<
+            <<R as pallet_evm::Config>::AccountProvider as fp_evm::AccountProvider>::AccountId,
+        >,
+    >,
+    A: Get<AssetId>,
+    W: WeightInfo,
+{
+    #[precompile::public("xcmSend(bytes,bytes)")]
+    fn xcm_send(
+        handle: &mut impl PrecompileHandle,
+        dest: LocationBytes,
+        message: MessageBytes,
+    ) -> EvmResult {
+        let len = dest.as_bytes().len() + message.as_bytes().len();
+        // The `send` dispatch is charged on its own by `try_dispatch`.
+        record_weight::<R>(handle, W::decode_message(len as u32))?;
+
+        let call = pallet_xcm::Call::<R>::send {
+            dest: Box::new(Self::decode_location(dest.as_bytes())?),
+            message: Box::new(Self::decode_message(message.as_bytes())?),
+        };
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("xcmExecute(bytes,uint64)")]
+    fn xcm_execute(
+        handle: &mut impl PrecompileHandle,
+        message: MessageBytes,
+        max_weight: u64,
+    ) -> EvmResult {
+        let len = message.as_bytes().len() as u32;
+        // The `execute` dispatch, `max_weight` included, is charged on its own by `try_dispatch`.
+        record_weight::<R>(
+            handle,
+            W::decode_message(len).saturating_add(W::weigh_message(len)),
+        )?;
+
+        // `maxWeight` only bounds the ref time: the proof size is the one the message needs.
+        let proof_size = Self::weigh(message.as_bytes())?.proof_size();
+        let call = pallet_xcm::Call::<R>::execute {
+            message: Box::new(Self::decode_message(message.as_bytes())?),
+            max_weight: Weight::from_parts(max_weight, proof_size),
+        };
+
+        dispatch_as_caller::<R, O, C>(handle, call.into())
+    }
+
+    #[precompile::public("weighMessage(bytes)")]
+    #[precompile::view]
+    fn weigh_message(handle: &mut impl PrecompileHandle, message: MessageBytes) -> EvmResult<u64> {
+        record_weight::<R>(handle, W::weigh_message(message.as_bytes().len() as u32))?;
+
+        Ok(Self::weigh(message.as_bytes())?.ref_time())
+    }
+
+    #[precompile::public("getUnitsPerSecond(bytes)")]
+    #[precompile::view]
+    fn get_units_per_second(
+        handle: &mut impl PrecompileHandle,
+        asset_location: LocationBytes,
+    ) -> EvmResult<U256> {
+        record_weight::<R>(handle, W::get_units_per_second())?;
+
+        Ok(Self::units_per_second(asset_location.as_bytes())?.into())
+    }
+}
+
+impl<R, O, C, A, W> XcmUtilsPrecompile<R, O, C, A, W>
+where
+    R: pallet_xcm::Config + pallet_transaction_payment::Config,
+    A: Get<AssetId>,
+{
+    fn decode_location(bytes: &[u8]) -> EvmResult<VersionedLocation> {
+        VersionedLocation::decode_all_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &*bytes)
+            .map_err(|_| revert("cannot decode location"))
+    }
+
+    fn decode_message<Call>(bytes: &[u8]) -> EvmResult<VersionedXcm<Call>> {
+        VersionedXcm::<Call>::decode_all_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut &*bytes)
+            .map_err(|_| revert("cannot decode message"))
+    }
+
+    fn weigh(message: &[u8]) -> EvmResult<Weight> {
+        pallet_xcm::Pallet::<R>::query_xcm_weight(Self::decode_message(message)?)
+            .map_err(|_| revert("cannot weigh message"))
+    }
+
+    /// The fee for one second of execution, payable only in the native asset.
+    fn units_per_second(asset_location: &[u8]) -> EvmResult<BalanceOf<R>> {
+        let location: Location = Self::decode_location(asset_location)?
+            .try_into()
+            .map_err(|_| RevertReason::custom("xcm conversion error"))?;
+        if location != A::get().0 {
+            return Err(revert("asset cannot pay for xcm execution"));
+        }
+
+        Ok(
+            <R as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+                &Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0),
+            ),
+        )
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2025, Horizen Labs, Inc.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of the work the XCM utils precompile does on top of the EVM handle.
+//! Benchmarks are defined on a benchmarking-only pallet, as the precompile has no storage nor
+//! calls of its own.
+
+use super::*;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use parity_scale_codec::Encode;
+use xcm::v5::{Instruction::Transact, OriginKind, Xcm};
+
+pub struct Pallet<T: Config>(PhantomData<T>);
+
+pub trait Config: pallet_xcm::Config + pallet_transaction_payment::Config {
+    /// Asset paying for the XCM execution, as configured in the precompile.
+    type Asset: Get<AssetId>;
+}
+
+type Precompile<T> = XcmUtilsPrecompile<T, (), (), <T as Config>::Asset, ()>;
+
+/// The most expensive message to weigh for its size: a `Transact` whose call is decoded and
+/// weighed as well.
+fn message_of_len<T: Config>(n: u32) -> Vec<u8> {
+    let call = <T as frame_system::Config>::RuntimeCall::from(frame_system::Call::<T>::remark {
+        remark: vec![0; n as usize],
+    });
+    VersionedXcm::V5(Xcm::<()>(vec![Transact {
+        origin_kind: OriginKind::SovereignAccount,
+        fallback_max_weight: None,
+        call: call.encode().into(),
+    }]))
+    .encode()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn decode_message(n: Linear<0, { MAX_MESSAGE_LEN - 100 }>) {
+        let message = message_of_len::<T>(n);
+        let decoded;
+
+        #[block]
+        {
+            decoded = Precompile::<T>::decode_message::<()>(&message);
+        }
+
+        assert!(decoded.is_ok());
+    }
+
+    #[benchmark]
+    fn weigh_message(n: Linear<0, { MAX_MESSAGE_LEN - 100 }>) {
+        let message = message_of_len::<T>(n);
+        let weight;
+
+        #[block]
+        {
+            weight = Precompile::<T>::weigh(&message);
+        }
+
+        assert!(weight.is_ok());
+    }
+
+    #[benchmark]
+    fn get_units_per_second() {
+        let location = VersionedLocation::V5(T::Asset::get().0).encode();
+        let units;
+
+        #[block]
+        {
+            units = Precompile::<T>::units_per_second(&location);
+        }
+
+        assert!(units.is_ok());
+    }
+}
@@ -0,0 +1,39 @@
+//! Weights for `xcm_utils_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet xcm_utils_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `xcm_utils_precompile`.
+pub trait WeightInfo {
+    fn decode_message(n: u32, ) -> Weight;
+    fn weigh_message(n: u32, ) -> Weight;
+    fn get_units_per_second() -> Weight;
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// The range of component `n` is `[0, 65436]`.
+    fn decode_message(n: u32, ) -> Weight {
+        Weight::from_parts(1_402_000, 0)
+            .saturating_add(Weight::from_parts(153, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 65436]`.
+    fn weigh_message(n: u32, ) -> Weight {
+        Weight::from_parts(3_893_000, 0)
+            .saturating_add(Weight::from_parts(462, 0).saturating_mul(n.into()))
+    }
+    fn get_units_per_second() -> Weight {
+        Weight::from_parts(1_108_000, 0)
+    }
+}
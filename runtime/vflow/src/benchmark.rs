@@ -39,4 +39,7 @@ frame_benchmarking::define_benchmarks!(
     [pallet_deployment_permissions, DeploymentPermissions]
     [pallet_xcm_benchmarks::generic, xcm::XcmPalletBenchGeneric::<Runtime>]
     [pallet_xcm_benchmarks::fungible, xcm::XcmPalletBenchFungible::<Runtime>]
+    [call_permit_precompile, CallPermitPrecompileBench::<Runtime>]
+    [xcm_teleport_precompile, XcmTeleportPrecompileBench::<Runtime>]
+    [xcm_utils_precompile, XcmUtilsPrecompileBench::<Runtime>]
 );
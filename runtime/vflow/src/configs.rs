@@ -32,6 +32,16 @@ cfg_if::cfg_if! {
 }
 pub use chain_cfg::*;
 
+/// Parameters of the mainnet network, available whatever network the runtime is built for.
+pub mod mainnet {
+    pub const EVM_CHAIN_ID: u64 = 1408;
+}
+
+/// Parameters of the Volta testnet, available whatever network the runtime is built for.
+pub mod volta {
+    pub const EVM_CHAIN_ID: u64 = 1409;
+}
+
 #[macro_export]
 macro_rules! runtime_version {
     ( $spec_name:tt ) => {
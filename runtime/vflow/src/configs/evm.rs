@@ -16,9 +16,10 @@
 //! In this module, we provide the configurations about evm.
 
 use crate::{
-    constants::{MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, WEIGHT_PER_GAS},
-    opaque, weights, AccountId, Aura, Balances, CollatorSelection, DeploymentPermissions,
-    EVMChainId, Precompiles, Runtime, Timestamp, TransactionPayment, UncheckedExtrinsic,
+    constants::{currency::deposit, MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, WEIGHT_PER_GAS},
+    opaque, weights, AccountId, Aura, Balance, Balances, CollatorSelection, DeploymentPermissions,
+    EVMChainId, Precompiles, Runtime, RuntimeHoldReason, Timestamp, TransactionPayment,
+    UncheckedExtrinsic,
 };
 use core::marker::PhantomData;
 use fp_evm::FeeCalculator;
@@ -27,13 +28,14 @@ use frame_support::{
     parameter_types,
     traits::{tokens::imbalance::ResolveTo, FindAuthor},
 };
+use frame_system::EnsureRoot;
 use pallet_ethereum::PostLogContent;
 use pallet_evm::{
     EVMFungibleAdapter, EnsureAccountId20, EnsureAddressRoot, IdentityAddressMapping,
 };
 use parity_scale_codec::{Decode, Encode};
 use sp_core::{H160, U256};
-use sp_runtime::{ConsensusEngineId, FixedPointNumber};
+use sp_runtime::{traits::ConvertInto, ConsensusEngineId, FixedPointNumber};
 use sp_weights::Weight;
 
 parameter_types! {
@@ -56,7 +58,25 @@ parameter_types! {
     pub StakingPot: AccountId = CollatorSelection::account_id();
 }
 
+parameter_types! {
+    // One `PendingRequests` entry, 109 bytes at most.
+    pub const DeployPermissionRequestDeposit: Balance = deposit(1, 109);
+    // One `ContractBonds` entry, 72 bytes at most.
+    pub const ContractDeploymentBond: Balance = deposit(1, 72);
+}
+
 impl pallet_deployment_permissions::Config for Runtime {
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type AccountIdToAddress = ConvertInto;
+    type AddressToAccountId = ConvertInto;
+    type MaxPrunedPerBlock = ConstU32<100>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type RequestDeposit = DeployPermissionRequestDeposit;
+    type MaxPendingRequests = ConstU32<100>;
+    type MaxBatchSize = ConstU32<100>;
+    type DeploymentBond = ContractDeploymentBond;
+    type HasCode = pallet_deployment_permissions::EvmHasCode<Self>;
     type WeightInfo = weights::pallet_deployment_permissions::ZKVEvmWeight<Self>;
 }
 
@@ -117,7 +137,7 @@ impl pallet_evm::Config for Runtime {
     type Timestamp = Timestamp;
     type WeightInfo = weights::pallet_evm::ZKVEvmWeight<Self>;
     type CreateOriginFilter = ();
-    type CreateInnerOriginFilter = ();
+    type CreateInnerOriginFilter = pallet_deployment_permissions::EnsureInnerCreateOrigin<Self>;
 }
 
 impl pallet_evm_chain_id::Config for Runtime {}
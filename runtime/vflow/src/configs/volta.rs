@@ -24,4 +24,4 @@ pub(crate) const ZKV_GENESIS_HASH: [u8; 32] =
 
 pub(crate) const ERC20_NAME: sp_runtime::Cow<'_, str> = Cow::Borrowed("tVFY token");
 pub(crate) const ERC20_SYMBOL: sp_runtime::Cow<'_, str> = Cow::Borrowed("tVFY");
-pub(crate) const EVM_CHAIN_ID: u64 = 1409;
+pub(crate) const EVM_CHAIN_ID: u64 = super::volta::EVM_CHAIN_ID;
@@ -29,15 +29,12 @@ use frame_support::{
     traits::tokens::imbalance::ResolveTo,
     traits::OriginTrait,
     traits::TransformOrigin,
-    traits::{ConstU32, Contains, Equals, Everything, Nothing, PalletInfoAccess},
+    traits::{ConstU32, Contains, ContainsPair, Equals, Everything, Nothing, PalletInfoAccess},
     weights::Weight,
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
-use parachains_common::{
-    message_queue::{NarrowOriginToSibling, ParaIdToSibling},
-    xcm_config::ConcreteAssetFromSystem,
-};
+use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
 use sp_runtime::{
     traits::{PostDispatchInfoOf, TryConvert},
     DispatchErrorWithPostInfo,
@@ -46,7 +43,7 @@ use vflow_runtime_common::types::AccountId;
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountKey20Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-    AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain, DenyThenTry,
+    AllowTopLevelPaidExecutionFrom, Case, DenyReserveTransferToRelayChain, DenyThenTry,
     DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FrameTransactionalProcessor,
     FungibleAdapter, HashedDescription, IsConcrete, ParentIsPreset, RelayChainAsNative,
     SendXcmFeeToAccount, SiblingParachainAsNative, SignedAccountKey20AsNative,
@@ -172,7 +169,40 @@ pub type Barrier = TrailingSetTopicAsId<
     >,
 >;
 
-pub type TrustedTeleporters = ConcreteAssetFromSystem<RelayLocation>;
+parameter_types! {
+    /// Para ids of the sibling zkVerify parachains, the only ones VFY is teleported with.
+    pub const ZkVerifyParaIds: &'static [u32] = &[2];
+}
+
+/// Sibling zkVerify parachains, as listed in [`ZkVerifyParaIds`].
+pub struct ZkVerifyParachains;
+impl Contains<u32> for ZkVerifyParachains {
+    fn contains(para_id: &u32) -> bool {
+        ZkVerifyParaIds::get().contains(para_id)
+    }
+}
+
+parameter_types! {
+    pub NativeAssetFromRelay: (AssetFilter, Location) = (
+        Wild(AllOf { fun: WildFungible, id: NativeAssetId::get() }),
+        RelayLocation::get(),
+    );
+}
+
+pub struct NativeAssetFromZkVerifyParachains;
+impl ContainsPair<Asset, Location> for NativeAssetFromZkVerifyParachains {
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        asset.id == NativeAssetId::get()
+            && matches!(origin.unpack(), (1, [Parachain(id)]) if ZkVerifyParachains::contains(id))
+    }
+}
+
+/// The native asset is teleported to and from the relay chain and the sibling zkVerify
+/// parachains.
+pub type TrustedTeleporters = (
+    Case<NativeAssetFromRelay>,
+    NativeAssetFromZkVerifyParachains,
+);
 
 pub type WaivedLocations = (Equals<RelayLocation>, Equals<RootLocation>);
 
@@ -390,7 +420,7 @@ parameter_types! {
 }
 
 /// Price For Sibling Parachain Delivery
-type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
+pub type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
     FeeAssetId,
     ToSiblingBaseDeliveryFee,
     TransactionByteFee,
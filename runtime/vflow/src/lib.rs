@@ -55,6 +55,10 @@ use frame_support::{
     weights::Weight,
 };
 pub use frame_system::Call as SystemCall;
+use pallet_deployment_permissions::{
+    runner::{PermissionedDeployError, REJECTED_TRANSACTION_CODE},
+    EnsureCreateOrigin,
+};
 use pallet_ethereum::{
     Call::transact, Transaction as EthereumTransaction, TransactionAction, TransactionData,
     TransactionStatus,
@@ -70,7 +74,9 @@ use sp_runtime::{
     traits::{
         Block as BlockT, DispatchInfoOf, Dispatchable, Get, PostDispatchInfoOf, UniqueSaturatedInto,
     },
-    transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
+    transaction_validity::{
+        InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+    },
     ApplyExtrinsicResult,
 };
 pub use sp_runtime::{Perbill, Permill};
@@ -115,7 +121,12 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
         len: usize,
     ) -> Option<TransactionValidity> {
         match self {
-            RuntimeCall::Ethereum(call) => call.validate_self_contained(info, dispatch_info, len),
+            RuntimeCall::Ethereum(call) => {
+                if let Err(error) = check_deployment_permissions(info, call) {
+                    return Some(Err(error));
+                }
+                call.validate_self_contained(info, dispatch_info, len)
+            }
             _ => None,
         }
     }
@@ -149,6 +160,25 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
     }
 }
 
+/// Rejects the Ethereum transactions the permissioned runner would reject already when they are
+/// validated, with a dedicated error code: RPC clients sending them get it back from the pool.
+fn check_deployment_permissions(
+    source: &H160,
+    call: &pallet_ethereum::Call<Runtime>,
+) -> Result<(), TransactionValidityError> {
+    let transact { transaction } = call else {
+        return Ok(());
+    };
+    let transaction: TransactionData = transaction.into();
+    match transaction.action {
+        TransactionAction::Create => {
+            DeploymentPermissions::check_create(source, &transaction.input)
+        }
+        TransactionAction::Call(target) => DeploymentPermissions::check_call(&target),
+    }
+    .map_err(|_| InvalidTransaction::Custom(REJECTED_TRANSACTION_CODE).into())
+}
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't
 /// need to know the specifics of the runtime. They can then be made to be
 /// agnostic over specific formats of data like extrinsics, allowing for them to
@@ -381,6 +411,43 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_deployment_permissions_runtime_api::DeploymentPermissionsApi<Block, AccountId, Balance, BlockNumber>
+        for Runtime
+    {
+        fn is_deployer(address: H160) -> bool {
+            DeploymentPermissions::is_deployer(&address)
+        }
+
+        fn deployers(
+            start_after: Option<H160>,
+            limit: u32,
+        ) -> Vec<(H160, pallet_deployment_permissions::DeployerInfo<BlockNumber>)> {
+            DeploymentPermissions::deployers(
+                start_after,
+                limit.min(pallet_deployment_permissions_runtime_api::MAX_DEPLOYERS_PER_PAGE),
+            )
+        }
+
+        fn deployment_mode() -> pallet_deployment_permissions::DeploymentMode {
+            pallet_deployment_permissions::Mode::<Runtime>::get()
+        }
+
+        fn deploy_quota(address: H160) -> Option<pallet_deployment_permissions::DeployQuota<BlockNumber>> {
+            pallet_deployment_permissions::DeployQuotas::<Runtime>::get(address)
+        }
+
+        fn pending_requests() -> Vec<(
+            H160,
+            pallet_deployment_permissions::DeployRequest<AccountId, Balance, BlockNumber>,
+        )> {
+            DeploymentPermissions::pending_requests()
+        }
+
+        fn deployment_bonds(deployer: H160) -> Balance {
+            DeploymentPermissions::deployment_bonds(deployer)
+        }
+    }
+
     impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
         fn convert_location(location: VersionedLocation) -> Result<AccountId, XcmConversionApiError> {
             LocationToAccountHelper::<
@@ -534,7 +601,12 @@ impl_runtime_apis! {
                 weight_limit,
                 proof_size_base_cost,
                 config.as_ref().unwrap_or(<Runtime as pallet_evm::Config>::config()),
-            ).map_err(|err| err.error.into())
+            ).or_else(|err| match err.error {
+                PermissionedDeployError::Permission(error) => {
+                    Ok(pallet_deployment_permissions::runner::rejected_call_info(error))
+                }
+                error => Err(error.into()),
+            })
         }
 
         /// Returns a frame_ethereum::create response.
@@ -588,7 +660,12 @@ impl_runtime_apis! {
                 weight_limit,
                 proof_size_base_cost,
                 config.as_ref().unwrap_or(<Runtime as pallet_evm::Config>::config()),
-            ).map_err(|err| err.error.into())
+            ).or_else(|err| match err.error {
+                PermissionedDeployError::Permission(error) => {
+                    Ok(pallet_deployment_permissions::runner::rejected_create_info(error))
+                }
+                error => Err(error.into()),
+            })
         }
 
         /// Return the current transaction status.
@@ -725,6 +802,9 @@ impl_runtime_apis! {
             use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
 
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use vflow_runtime_common::call_permit::benchmarking::Pallet as CallPermitPrecompileBench;
+            use vflow_runtime_common::xcm_teleport::benchmarking::Pallet as XcmTeleportPrecompileBench;
+            use vflow_runtime_common::xcm_utils::benchmarking::Pallet as XcmUtilsPrecompileBench;
 
             pub mod xcm {
                 pub use pallet_xcm_benchmarks::fungible::Pallet as XcmPalletBenchFungible;
@@ -746,6 +826,9 @@ impl_runtime_apis! {
             use frame_system_benchmarking::Pallet as SystemBench;
             use frame_system_benchmarking::extensions::Pallet as SystemExtensionsBench;
             use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
+            use vflow_runtime_common::call_permit::benchmarking::Pallet as CallPermitPrecompileBench;
+            use vflow_runtime_common::xcm_teleport::benchmarking::Pallet as XcmTeleportPrecompileBench;
+            use vflow_runtime_common::xcm_utils::benchmarking::Pallet as XcmUtilsPrecompileBench;
 
             pub mod xcm {
                 use super::*;
@@ -764,6 +847,7 @@ impl_runtime_apis! {
                     ).into());
                     /// The base fee for the message delivery fees. Kusama is based for the reference.
                     pub const ToParentBaseDeliveryFee: u128 = CENTS.saturating_mul(3);
+                    pub SiblingParaId: cumulus_primitives_core::ParaId = 2.into();
                 }
 
                 impl pallet_xcm::benchmarking::Config for Runtime {
@@ -803,6 +887,27 @@ impl_runtime_apis! {
                     }
                 }
 
+                impl vflow_runtime_common::xcm_teleport::benchmarking::Config for Runtime {
+                    type Destination = RelayLocation;
+                    type Asset = NativeAssetId;
+                    type Parachains = ZkVerifyParachains;
+                    type SiblingDeliveryHelper = cumulus_primitives_utility::ToParachainDeliveryHelper<
+                        XcmConfig,
+                        ExistentialDepositAsset,
+                        PriceForSiblingParachainDelivery,
+                        SiblingParaId,
+                        ParachainSystem,
+                    >;
+
+                    fn parachain() -> u32 {
+                        SiblingParaId::get().into()
+                    }
+                }
+
+                impl vflow_runtime_common::xcm_utils::benchmarking::Config for Runtime {
+                    type Asset = NativeAssetId;
+                }
+
                 impl pallet_xcm_benchmarks::Config for Runtime {
                     type XcmConfig = XcmConfig;
                     type AccountIdConverter = LocationAccountId32ToAccountId;
@@ -958,4 +1063,6 @@ mod runtime_benchmarking_extra_config {
     }
 
     impl cumulus_pallet_session_benchmarking::Config for Runtime {}
+
+    impl vflow_runtime_common::call_permit::benchmarking::Config for Runtime {}
 }
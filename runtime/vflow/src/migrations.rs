@@ -31,4 +31,7 @@ pub type Unreleased = (
         pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
     >,
     cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
+    pallet_deployment_permissions::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_deployment_permissions::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_deployment_permissions::migrations::v3::MigrateV2ToV3<Runtime>,
 );
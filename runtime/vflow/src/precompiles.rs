@@ -23,15 +23,20 @@ use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripe
 use precompile_utils::precompile_set::*;
 
 use crate::configs::{
-    xcm::{NativeAssetId, RelayLocation},
+    xcm::{NativeAssetId, RelayLocation, ZkVerifyParachains},
     ERC20_NAME, ERC20_SYMBOL,
 };
-use crate::{RuntimeCall, RuntimeOrigin};
-use vflow_runtime_common::xcm_teleport::XcmTeleportPrecompile;
+use crate::{weights, RuntimeCall, RuntimeOrigin};
+use vflow_runtime_common::{
+    call_permit::CallPermitPrecompile, collator_staking::CollatorStakingPrecompile,
+    deployment_permissions::DeploymentPermissionsPrecompile, xcm_teleport::XcmTeleportPrecompile,
+    xcm_utils::XcmUtilsPrecompile,
+};
 
 pub struct NativeErc20Metadata;
 
-/// ERC20 metadata for the native token.
+/// ERC20 metadata for the native token. The name is also the one of the EIP-2612 `permit` domain,
+/// which is bound to the chain id in `EVMChainId`.
 impl Erc20Metadata for NativeErc20Metadata {
     /// Returns the name of the token.
     fn name() -> &'static str {
@@ -88,9 +93,48 @@ type PrecompilesAt<R> = (
             CallableByPrecompile<OnlyFrom<AddressU64<2056>>>,
         ),
     >,
+    PrecompileAt<
+        AddressU64<2058>,
+        CallPermitPrecompile<R, weights::call_permit_precompile::ZKVEvmWeight<R>>,
+        (
+            SubcallWithMaxNesting<0>,
+            CallableByContract,
+            // Relayers can batch permits.
+            CallableByPrecompile<OnlyFrom<AddressU64<2056>>>,
+        ),
+    >,
     PrecompileAt<
         AddressU64<2060>,
-        XcmTeleportPrecompile<R, RuntimeOrigin, RuntimeCall, RelayLocation, NativeAssetId>,
+        XcmTeleportPrecompile<
+            R,
+            RuntimeOrigin,
+            RuntimeCall,
+            RelayLocation,
+            NativeAssetId,
+            ZkVerifyParachains,
+            weights::xcm_teleport_precompile::ZKVEvmWeight<R>,
+        >,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2061>,
+        DeploymentPermissionsPrecompile<R, RuntimeOrigin, RuntimeCall>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2062>,
+        XcmUtilsPrecompile<
+            R,
+            RuntimeOrigin,
+            RuntimeCall,
+            NativeAssetId,
+            weights::xcm_utils_precompile::ZKVEvmWeight<R>,
+        >,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2063>,
+        CollatorStakingPrecompile<R, RuntimeOrigin, RuntimeCall>,
         (CallableByContract, CallableByPrecompile),
     >,
 );
@@ -99,6 +143,6 @@ pub type Precompiles<R> = PrecompileSetBuilder<
     R,
     (
         // Skip precompiles if out of range.
-        PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<2060>), PrecompilesAt<R>>,
+        PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<2063>), PrecompilesAt<R>>,
     ),
 >;
@@ -22,7 +22,11 @@ use crate::{
 };
 use frame_support::weights::Weight;
 
+mod call_permit_precompile;
+mod collator_staking_precompile;
 mod constants_test;
+mod deployment_permissions_precompile;
+mod erc20_permit;
 mod multiplier;
 mod permissioned_deploy;
 mod storage;
@@ -30,6 +34,7 @@ mod use_correct_weights;
 mod xcm_eth;
 mod xcm_runtime_apis_impl;
 mod xcm_teleport_integration;
+mod xcm_utils_precompile;
 
 mod misc {
     use super::*;
@@ -138,3 +143,139 @@ pub fn unchecked_eth_tx(raw_hex_tx: &str) -> UncheckedExtrinsic {
     let converter = TransactionConverter;
     converter.convert_transaction(ethereum_transaction(raw_hex_tx))
 }
+
+/// Minimal RLP encoding, enough to build the Ethereum transactions signed by the tests.
+pub mod rlp {
+    use sp_core::U256;
+
+    /// Encodes a byte string.
+    pub fn bytes(data: &[u8]) -> Vec<u8> {
+        match data {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ => [prefix(0x80, data.len()), data.to_vec()].concat(),
+        }
+    }
+
+    /// Encodes an integer, as its big-endian bytes without leading zeros.
+    pub fn uint(value: impl Into<U256>) -> Vec<u8> {
+        let be = value.into().to_big_endian();
+        let first = be.iter().position(|byte| *byte != 0).unwrap_or(be.len());
+        bytes(&be[first..])
+    }
+
+    /// Encodes a list of already encoded items.
+    pub fn list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload = items.concat();
+        [prefix(0xc0, payload.len()), payload].concat()
+    }
+
+    fn prefix(offset: u8, len: usize) -> Vec<u8> {
+        if len < 56 {
+            return vec![offset + len as u8];
+        }
+        let len = (len as u64).to_be_bytes();
+        let first = len.iter().position(|byte| *byte != 0).unwrap_or(len.len());
+        [
+            vec![offset + 55 + (len.len() - first) as u8],
+            len[first..].to_vec(),
+        ]
+        .concat()
+    }
+}
+
+/// Returns the Ethereum address of the account owning `pair`.
+pub fn eth_address(pair: &sp_core::ecdsa::Pair) -> sp_core::H160 {
+    use sp_core::Pair;
+
+    let hash = [0u8; 32];
+    let signature: [u8; 65] = pair.sign_prehashed(&hash).as_ref().try_into().unwrap();
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &hash).unwrap();
+    sp_core::H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..])
+}
+
+/// Signs with `pair` an EIP-1559 transaction running `input` as `action`, with the next nonce of
+/// the signer and the minimum gas price.
+pub fn signed_eth_transaction(
+    pair: &sp_core::ecdsa::Pair,
+    action: ethereum::TransactionAction,
+    input: Vec<u8>,
+) -> pallet_ethereum::Transaction {
+    let to = match action {
+        ethereum::TransactionAction::Call(target) => target.as_bytes().to_vec(),
+        ethereum::TransactionAction::Create => Vec::new(),
+    };
+    sign_eth_transaction(pair, 0x02, transaction_fields(pair, &to, &input))
+}
+
+/// Signs with `pair` an EIP-7702 transaction installing `authorization_list` and calling `target`
+/// with `input`, with the next nonce of the signer and the minimum gas price.
+pub fn signed_set_code_transaction(
+    pair: &sp_core::ecdsa::Pair,
+    target: sp_core::H160,
+    input: Vec<u8>,
+    authorization_list: &[ethereum::AuthorizationListItem],
+) -> pallet_ethereum::Transaction {
+    let authorizations: Vec<_> = authorization_list
+        .iter()
+        .map(|item| {
+            rlp::list(&[
+                rlp::uint(item.chain_id),
+                rlp::bytes(item.address.as_bytes()),
+                rlp::uint(item.nonce),
+                rlp::uint(item.signature.odd_y_parity as u8),
+                rlp::uint(sp_core::U256::from_big_endian(item.signature.r.as_bytes())),
+                rlp::uint(sp_core::U256::from_big_endian(item.signature.s.as_bytes())),
+            ])
+        })
+        .collect();
+    let fields = [
+        transaction_fields(pair, target.as_bytes(), &input),
+        vec![rlp::list(&authorizations)],
+    ]
+    .concat();
+    sign_eth_transaction(pair, 0x04, fields)
+}
+
+/// Returns the fields EIP-1559 and EIP-7702 transactions share, up to the access list.
+fn transaction_fields(pair: &sp_core::ecdsa::Pair, to: &[u8], input: &[u8]) -> Vec<Vec<u8>> {
+    use pallet_evm::FeeCalculator;
+    use sp_core::Get;
+
+    let (account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&eth_address(pair));
+    let (gas_price, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+    vec![
+        rlp::uint(<Runtime as pallet_evm::Config>::ChainId::get()),
+        rlp::uint(account.nonce),
+        rlp::uint(0u8),
+        rlp::uint(gas_price),
+        rlp::uint(1_000_000u64),
+        rlp::bytes(to),
+        rlp::uint(0u8),
+        rlp::bytes(input),
+        rlp::list(&[]),
+    ]
+}
+
+/// Appends the signature by `pair` to the `fields` of a typed transaction and decodes it.
+fn sign_eth_transaction(
+    pair: &sp_core::ecdsa::Pair,
+    transaction_type: u8,
+    fields: Vec<Vec<u8>>,
+) -> pallet_ethereum::Transaction {
+    use sp_core::Pair;
+
+    let signed = |fields: &[Vec<u8>]| [vec![transaction_type], rlp::list(fields)].concat();
+
+    let hash = sp_io::hashing::keccak_256(&signed(&fields));
+    let signature: [u8; 65] = pair.sign_prehashed(&hash).as_ref().try_into().unwrap();
+    let fields = [
+        fields,
+        vec![
+            rlp::uint(signature[64]),
+            rlp::uint(sp_core::U256::from_big_endian(&signature[..32])),
+            rlp::uint(sp_core::U256::from_big_endian(&signature[32..64])),
+        ],
+    ]
+    .concat();
+    ethereum::EnvelopedDecodable::decode(&signed(&fields)).expect("transaction is well formed")
+}
@@ -0,0 +1,218 @@
+use super::*;
+use crate::{
+    constants::currency::{CENTS, MICROCENTS, VFY},
+    tests::{ExtBuilder, BOB},
+    Balances, RuntimeEvent, RuntimeOrigin,
+};
+use alloy::primitives::{Address as SolAddress, Bytes, FixedBytes, U256 as Uint256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use fp_evm::CallInfo;
+use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::assert_ok;
+use precompile_utils::precompile_set::AddressU64;
+use sp_core::{ecdsa, keccak_256, Get, Pair, H160, U256};
+use vflow_runtime_common::call_permit::{CallPermitPrecompile, PERMIT_DOMAIN};
+
+sol! {
+    contract ICallPermit {
+        function dispatch(address from, address to, uint256 value, bytes data, uint64 gaslimit, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external returns (bytes);
+        function nonces(address owner) external view returns (uint256);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+    }
+
+    contract IBatch {
+        function batchAll(address[] to, uint256[] value, bytes[] callData, uint64[] gasLimit) external;
+    }
+}
+
+type Precompile = CallPermitPrecompile<Runtime, ()>;
+
+const CHARLIE: [u8; 20] = [6u8; 20];
+const GAS_LIMIT: u64 = 100_000;
+
+fn precompile_address() -> H160 {
+    AddressU64::<2058>::get()
+}
+
+fn signer() -> (ecdsa::Pair, H160) {
+    let pair = ecdsa::Pair::from_seed(&[0x42; 32]);
+    let address = AccountId::from(pair.public()).into();
+    (pair, address)
+}
+
+fn view(calldata: Vec<u8>) -> Vec<u8> {
+    let CallInfo { value, .. } = Runtime::call(
+        BOB.into(),
+        precompile_address(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    value
+}
+
+fn transact(to: H160, calldata: Vec<u8>) {
+    assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+        RuntimeOrigin::root(),
+        BOB.into(),
+        to,
+        calldata,
+        0.into(),
+        1_000_000,
+        (100 * MICROCENTS).into(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    ));
+}
+
+fn executed_successfully() -> bool {
+    System::events().into_iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { .. })
+        )
+    })
+}
+
+fn nonce(owner: H160) -> U256 {
+    let calldata = ICallPermit::noncesCall {
+        owner: owner.0.into(),
+    }
+    .abi_encode();
+    U256::from_big_endian(&view(calldata))
+}
+
+/// Calldata of `dispatch` for a permit, signed by `signer`, to transfer `value` to CHARLIE.
+fn signed_transfer(value: u128, nonce: U256) -> Vec<u8> {
+    let (pair, from) = signer();
+    let deadline = U256::MAX;
+    let permit = Precompile::generate_permit(
+        precompile_address(),
+        from,
+        CHARLIE.into(),
+        value.into(),
+        &[],
+        GAS_LIMIT,
+        nonce,
+        deadline,
+    );
+    let signature = pair.sign_prehashed(&permit);
+
+    ICallPermit::dispatchCall {
+        from: from.0.into(),
+        to: CHARLIE.into(),
+        value: Uint256::from(value),
+        data: Bytes::new(),
+        gaslimit: GAS_LIMIT,
+        deadline: Uint256::MAX,
+        v: signature.0[64],
+        r: FixedBytes::from_slice(&signature.0[0..32]),
+        s: FixedBytes::from_slice(&signature.0[32..64]),
+    }
+    .abi_encode()
+}
+
+fn ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (BOB.into(), 1_000 * CENTS),
+            (AccountId::from(signer().1), 10 * VFY),
+        ])
+        .build();
+    // Events are not recorded on the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn domain_separator_follows_eip_712() {
+    ext().execute_with(|| {
+        let expected = keccak_256(
+            &(
+                FixedBytes::<32>::from(PERMIT_DOMAIN),
+                FixedBytes::<32>::from(keccak_256(b"Call Permit Precompile")),
+                FixedBytes::<32>::from(keccak_256(b"1")),
+                Uint256::from(<Runtime as pallet_evm::Config>::ChainId::get()),
+                SolAddress::from(precompile_address().0),
+            )
+                .abi_encode_params(),
+        );
+
+        let calldata = ICallPermit::DOMAIN_SEPARATORCall {}.abi_encode();
+        assert_eq!(view(calldata), expected.to_vec());
+    });
+}
+
+#[test]
+fn relayer_dispatches_signed_call() {
+    ext().execute_with(|| {
+        let from = signer().1;
+        assert_eq!(nonce(from), U256::zero());
+
+        transact(precompile_address(), signed_transfer(VFY, U256::zero()));
+
+        assert!(executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), VFY);
+        assert_eq!(nonce(from), U256::one());
+    });
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+    ext().execute_with(|| {
+        let calldata = signed_transfer(VFY, U256::zero());
+        transact(precompile_address(), calldata.clone());
+        System::reset_events();
+
+        transact(precompile_address(), calldata);
+
+        assert!(!executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), VFY);
+        assert_eq!(nonce(signer().1), U256::one());
+    });
+}
+
+#[test]
+fn permit_of_another_account_is_rejected() {
+    ext().execute_with(|| {
+        let mut calldata = signed_transfer(VFY, U256::zero());
+        // Replace `from` with BOB: the signature doesn't match anymore.
+        calldata[16..36].copy_from_slice(&BOB);
+
+        transact(precompile_address(), calldata);
+
+        assert!(!executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), 0);
+    });
+}
+
+#[test]
+fn relayer_batches_permits() {
+    ext().execute_with(|| {
+        let calldata = IBatch::batchAllCall {
+            to: vec![precompile_address().0.into(); 2],
+            value: vec![],
+            callData: vec![
+                signed_transfer(VFY, U256::zero()).into(),
+                signed_transfer(2 * VFY, U256::one()).into(),
+            ],
+            gasLimit: vec![],
+        }
+        .abi_encode();
+
+        transact(AddressU64::<2056>::get(), calldata);
+
+        assert!(executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(CHARLIE)), 3 * VFY);
+        assert_eq!(nonce(signer().1), U256::from(2));
+    });
+}
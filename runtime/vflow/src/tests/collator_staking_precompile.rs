@@ -0,0 +1,231 @@
+use super::*;
+use crate::{
+    constants::currency::{MICROCENTS, VFY},
+    tests::{ExtBuilder, ALICE, BOB},
+    AuraId, Balances, RuntimeEvent, RuntimeOrigin, SessionKeys,
+};
+use alloy::primitives::{Address as SolAddress, Bytes, U256 as Uint256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use fp_evm::CallInfo;
+use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::{assert_ok, BoundedVec};
+use pallet_collator_selection::CandidateInfo;
+use parity_scale_codec::Encode;
+use precompile_utils::precompile_set::AddressU64;
+use sp_core::{sr25519, Get, H160, U256};
+
+sol! {
+    contract ICollatorStaking {
+        struct Candidate {
+            address account;
+            uint256 deposit;
+        }
+
+        function candidates() external view returns (Candidate[] memory);
+        function invulnerables() external view returns (address[] memory);
+        function candidacyBond() external view returns (uint256);
+        function registerAsCandidate() external;
+        function leaveIntent() external;
+        function updateBond(uint256 newDeposit) external;
+        function takeCandidateSlot(uint256 deposit, address target) external;
+        function setKeys(bytes keys, bytes proof) external;
+    }
+}
+
+const BOND: u128 = 10 * VFY;
+
+fn precompile_address() -> H160 {
+    AddressU64::<2063>::get()
+}
+
+fn view(calldata: Vec<u8>) -> Vec<u8> {
+    let CallInfo { value, .. } = Runtime::call(
+        ALICE.into(),
+        precompile_address(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    value
+}
+
+fn transact(caller: [u8; 20], calldata: Vec<u8>) {
+    assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+        RuntimeOrigin::root(),
+        caller.into(),
+        precompile_address(),
+        calldata,
+        0.into(),
+        1_000_000,
+        (100 * MICROCENTS).into(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    ));
+}
+
+fn executed_successfully() -> bool {
+    System::events().into_iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { .. })
+        )
+    })
+}
+
+fn candidates() -> Vec<(SolAddress, Uint256)> {
+    let value = view(ICollatorStaking::candidatesCall {}.abi_encode());
+    ICollatorStaking::candidatesCall::abi_decode_returns(&value)
+        .unwrap()
+        .into_iter()
+        .map(|candidate| (candidate.account, candidate.deposit))
+        .collect()
+}
+
+fn set_keys(caller: [u8; 20], aura: [u8; 32]) {
+    let keys = SessionKeys {
+        aura: AuraId::from(sr25519::Public::from_raw(aura)),
+    };
+    let calldata = ICollatorStaking::setKeysCall {
+        keys: keys.encode().into(),
+        proof: Bytes::new(),
+    }
+    .abi_encode();
+    transact(caller, calldata);
+}
+
+fn register_as_candidate(caller: [u8; 20]) {
+    set_keys(caller, [caller[0]; 32]);
+    transact(
+        caller,
+        ICollatorStaking::registerAsCandidateCall {}.abi_encode(),
+    );
+}
+
+fn ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * VFY), (BOB.into(), 1_000 * VFY)])
+        .build();
+    ext.execute_with(|| {
+        pallet_collator_selection::CandidacyBond::<Runtime>::put(BOND);
+        // Events are not recorded on the genesis block.
+        System::set_block_number(1);
+    });
+    ext
+}
+
+#[test]
+fn views_reflect_pallet_storage() {
+    ext().execute_with(|| {
+        pallet_collator_selection::Invulnerables::<Runtime>::put(BoundedVec::truncate_from(vec![
+            AccountId::from(BOB),
+        ]));
+        pallet_collator_selection::CandidateList::<Runtime>::put(BoundedVec::truncate_from(vec![
+            CandidateInfo {
+                who: AccountId::from(ALICE),
+                deposit: BOND,
+            },
+        ]));
+
+        assert_eq!(candidates(), vec![(ALICE.into(), Uint256::from(BOND))]);
+        let invulnerables = view(ICollatorStaking::invulnerablesCall {}.abi_encode());
+        assert_eq!(
+            ICollatorStaking::invulnerablesCall::abi_decode_returns(&invulnerables).unwrap(),
+            vec![SolAddress::from(BOB)]
+        );
+        let bond = view(ICollatorStaking::candidacyBondCall {}.abi_encode());
+        assert_eq!(Uint256::abi_decode(&bond).unwrap(), Uint256::from(BOND));
+    });
+}
+
+#[test]
+fn caller_registers_and_leaves_as_candidate() {
+    ext().execute_with(|| {
+        // Leaving must not drop the collators below `MinEligibleCollators`.
+        pallet_collator_selection::Invulnerables::<Runtime>::put(BoundedVec::truncate_from(vec![
+            AccountId::from(BOB),
+        ]));
+
+        register_as_candidate(ALICE);
+
+        assert!(executed_successfully());
+        assert_eq!(candidates(), vec![(ALICE.into(), Uint256::from(BOND))]);
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), BOND);
+
+        transact(ALICE, ICollatorStaking::leaveIntentCall {}.abi_encode());
+
+        assert!(candidates().is_empty());
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+    });
+}
+
+#[test]
+fn caller_without_session_keys_cannot_register() {
+    ext().execute_with(|| {
+        transact(
+            ALICE,
+            ICollatorStaking::registerAsCandidateCall {}.abi_encode(),
+        );
+
+        assert!(!executed_successfully());
+        assert!(candidates().is_empty());
+    });
+}
+
+#[test]
+fn candidate_updates_bond() {
+    ext().execute_with(|| {
+        register_as_candidate(ALICE);
+
+        let calldata = ICollatorStaking::updateBondCall {
+            newDeposit: Uint256::from(2 * BOND),
+        }
+        .abi_encode();
+        transact(ALICE, calldata);
+
+        assert_eq!(candidates(), vec![(ALICE.into(), Uint256::from(2 * BOND))]);
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 2 * BOND);
+    });
+}
+
+#[test]
+fn candidate_slot_is_taken_with_a_higher_deposit() {
+    ext().execute_with(|| {
+        register_as_candidate(ALICE);
+        set_keys(BOB, [BOB[0]; 32]);
+
+        let calldata = ICollatorStaking::takeCandidateSlotCall {
+            deposit: Uint256::from(2 * BOND),
+            target: ALICE.into(),
+        }
+        .abi_encode();
+        transact(BOB, calldata);
+
+        assert_eq!(candidates(), vec![(BOB.into(), Uint256::from(2 * BOND))]);
+        assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+    });
+}
+
+#[test]
+fn undecodable_session_keys_are_rejected() {
+    ext().execute_with(|| {
+        let calldata = ICollatorStaking::setKeysCall {
+            keys: vec![0xff; 3].into(),
+            proof: Bytes::new(),
+        }
+        .abi_encode();
+
+        transact(ALICE, calldata);
+
+        assert!(!executed_successfully());
+        assert!(pallet_session::NextKeys::<Runtime>::get(AccountId::from(ALICE)).is_none());
+    });
+}
@@ -0,0 +1,188 @@
+use super::*;
+use crate::{
+    constants::currency::{CENTS, MICROCENTS},
+    tests::{ExtBuilder, ALICE, BOB},
+    RuntimeEvent, RuntimeOrigin,
+};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use fp_evm::CallInfo;
+use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::assert_ok;
+use pallet_deployment_permissions::DeploymentMode;
+use precompile_utils::precompile_set::AddressU64;
+use sp_core::{Get, H160, H256, U256};
+use vflow_runtime_common::deployment_permissions::{
+    SELECTOR_LOG_DEPLOY_PERMISSION_GRANTED, SELECTOR_LOG_DEPLOY_PERMISSION_REVOKED,
+};
+
+sol! {
+    contract IDeploymentPermissions {
+        function isDeployer(address account) external view returns (bool);
+        function deploymentMode() external view returns (uint8);
+        function grant(address account) external;
+        function revoke(address account) external;
+    }
+}
+
+fn precompile_address() -> H160 {
+    AddressU64::<2061>::get()
+}
+
+fn view(calldata: Vec<u8>) -> Vec<u8> {
+    let CallInfo { value, .. } = Runtime::call(
+        ALICE.into(),
+        precompile_address(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    value
+}
+
+fn transact(caller: H160, calldata: Vec<u8>) {
+    assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+        RuntimeOrigin::root(),
+        caller,
+        precompile_address(),
+        calldata,
+        0.into(),
+        1_000_000,
+        (100 * MICROCENTS).into(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    ));
+}
+
+fn executed_successfully() -> bool {
+    System::events().into_iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { .. })
+        )
+    })
+}
+
+fn last_log() -> pallet_evm::Log {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => Some(log),
+            _ => None,
+        })
+        .expect("a log has been emitted")
+}
+
+#[test]
+fn is_deployer_reflects_pallet_storage() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                BOB.into(),
+            )
+            .unwrap();
+
+            let is_deployer = |account: [u8; 20]| {
+                let calldata = IDeploymentPermissions::isDeployerCall {
+                    account: account.into(),
+                }
+                .abi_encode();
+                bool::abi_decode(&view(calldata)).unwrap()
+            };
+            assert!(is_deployer(BOB));
+            assert!(!is_deployer(ALICE));
+        });
+}
+
+#[test]
+fn deployment_mode_reflects_pallet_storage() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::set_deployment_mode(
+                RuntimeOrigin::root(),
+                DeploymentMode::Frozen,
+            )
+            .unwrap();
+
+            let calldata = IDeploymentPermissions::deploymentModeCall {}.abi_encode();
+            let mode = u8::abi_decode(&view(calldata)).unwrap();
+            assert_eq!(mode, DeploymentMode::Frozen as u8);
+        });
+}
+
+#[test]
+fn manager_can_grant_and_revoke() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::add_manager(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+
+            let calldata = IDeploymentPermissions::grantCall {
+                account: BOB.into(),
+            }
+            .abi_encode();
+            transact(ALICE.into(), calldata);
+
+            assert!(executed_successfully());
+            assert!(pallet_deployment_permissions::Pallet::<Runtime>::is_deployer(&BOB.into()));
+            let log = last_log();
+            assert_eq!(log.address, precompile_address());
+            assert_eq!(
+                log.topics,
+                vec![
+                    H256(SELECTOR_LOG_DEPLOY_PERMISSION_GRANTED),
+                    H160::from(BOB).into(),
+                    H160::from(ALICE).into(),
+                ]
+            );
+            assert_eq!(log.data, H256::zero().as_bytes());
+
+            let calldata = IDeploymentPermissions::revokeCall {
+                account: BOB.into(),
+            }
+            .abi_encode();
+            transact(ALICE.into(), calldata);
+
+            assert!(!pallet_deployment_permissions::Pallet::<Runtime>::is_deployer(&BOB.into()));
+            assert_eq!(
+                last_log().topics[0],
+                H256(SELECTOR_LOG_DEPLOY_PERMISSION_REVOKED)
+            );
+        });
+}
+
+#[test]
+fn non_manager_cannot_grant() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let calldata = IDeploymentPermissions::grantCall {
+                account: BOB.into(),
+            }
+            .abi_encode();
+            transact(ALICE.into(), calldata);
+
+            assert!(!executed_successfully());
+            assert!(!pallet_deployment_permissions::Pallet::<Runtime>::is_deployer(&BOB.into()));
+        });
+}
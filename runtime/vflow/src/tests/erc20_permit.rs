@@ -0,0 +1,225 @@
+use super::*;
+use crate::{
+    configs::{mainnet, volta, ERC20_NAME},
+    constants::currency::{CENTS, MICROCENTS, VFY},
+    tests::{ExtBuilder, BOB},
+    RuntimeEvent, RuntimeOrigin,
+};
+use alloy::primitives::{Address as SolAddress, FixedBytes, U256 as Uint256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use fp_evm::CallInfo;
+use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::assert_ok;
+use precompile_utils::precompile_set::AddressU64;
+use rstest::rstest;
+use sp_core::{ecdsa, keccak_256, Get, Pair, H160, U256};
+
+sol! {
+    contract IERC20Permit {
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
+        function nonces(address owner) external view returns (uint256);
+        function DOMAIN_SEPARATOR() external view returns (bytes32);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+}
+
+const MAINNET_CHAIN_ID: u64 = mainnet::EVM_CHAIN_ID;
+const VOLTA_CHAIN_ID: u64 = volta::EVM_CHAIN_ID;
+
+fn precompile_address() -> H160 {
+    AddressU64::<2050>::get()
+}
+
+fn owner() -> (ecdsa::Pair, H160) {
+    let pair = ecdsa::Pair::from_seed(&[0x42; 32]);
+    let address = AccountId::from(pair.public()).into();
+    (pair, address)
+}
+
+fn view(calldata: Vec<u8>) -> Vec<u8> {
+    let CallInfo { value, .. } = Runtime::call(
+        BOB.into(),
+        precompile_address(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap();
+    value
+}
+
+fn transact(calldata: Vec<u8>) {
+    assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+        RuntimeOrigin::root(),
+        BOB.into(),
+        precompile_address(),
+        calldata,
+        0.into(),
+        1_000_000,
+        (100 * MICROCENTS).into(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    ));
+}
+
+fn executed_successfully() -> bool {
+    System::events().into_iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { .. })
+        )
+    })
+}
+
+fn domain_separator(chain_id: u64) -> [u8; 32] {
+    keccak_256(
+        &(
+            FixedBytes::<32>::from(keccak_256(
+                b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+            )),
+            FixedBytes::<32>::from(keccak_256(ERC20_NAME.as_bytes())),
+            FixedBytes::<32>::from(keccak_256(b"1")),
+            Uint256::from(chain_id),
+            SolAddress::from(precompile_address().0),
+        )
+            .abi_encode_params(),
+    )
+}
+
+fn nonce(owner: H160) -> U256 {
+    let calldata = IERC20Permit::noncesCall {
+        owner: owner.0.into(),
+    }
+    .abi_encode();
+    U256::from_big_endian(&view(calldata))
+}
+
+fn allowance(owner: H160, spender: H160) -> U256 {
+    let calldata = IERC20Permit::allowanceCall {
+        owner: owner.0.into(),
+        spender: spender.0.into(),
+    }
+    .abi_encode();
+    U256::from_big_endian(&view(calldata))
+}
+
+/// Calldata of `permit` for BOB to spend `value` of the owner, signed for `chain_id`.
+fn signed_permit(chain_id: u64, value: u128, nonce: u64) -> Vec<u8> {
+    let (pair, owner) = owner();
+    let permit_content = keccak_256(
+        &(
+            FixedBytes::<32>::from(keccak_256(
+                b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+            )),
+            SolAddress::from(owner.0),
+            SolAddress::from(BOB),
+            Uint256::from(value),
+            Uint256::from(nonce),
+            Uint256::MAX,
+        )
+            .abi_encode_params(),
+    );
+    let digest = keccak_256(
+        &[
+            &b"\x19\x01"[..],
+            &domain_separator(chain_id),
+            &permit_content,
+        ]
+        .concat(),
+    );
+    let signature = pair.sign_prehashed(&digest);
+
+    IERC20Permit::permitCall {
+        owner: owner.0.into(),
+        spender: BOB.into(),
+        value: Uint256::from(value),
+        deadline: Uint256::MAX,
+        v: signature.0[64],
+        r: FixedBytes::from_slice(&signature.0[0..32]),
+        s: FixedBytes::from_slice(&signature.0[32..64]),
+    }
+    .abi_encode()
+}
+
+fn ext(chain_id: u64) -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (BOB.into(), 1_000 * CENTS),
+            (AccountId::from(owner().1), 10 * VFY),
+        ])
+        .build();
+    ext.execute_with(|| {
+        pallet_evm_chain_id::ChainId::<Runtime>::put(chain_id);
+        // Events are not recorded on the genesis block.
+        System::set_block_number(1);
+    });
+    ext
+}
+
+#[rstest]
+#[case::mainnet(MAINNET_CHAIN_ID)]
+#[case::volta(VOLTA_CHAIN_ID)]
+fn domain_separator_uses_token_name_and_chain_id(#[case] chain_id: u64) {
+    ext(chain_id).execute_with(|| {
+        let calldata = IERC20Permit::DOMAIN_SEPARATORCall {}.abi_encode();
+
+        assert_eq!(view(calldata), domain_separator(chain_id).to_vec());
+    });
+}
+
+#[rstest]
+#[case::mainnet(MAINNET_CHAIN_ID)]
+#[case::volta(VOLTA_CHAIN_ID)]
+fn permit_approves_spender(#[case] chain_id: u64) {
+    ext(chain_id).execute_with(|| {
+        let owner = owner().1;
+        assert_eq!(nonce(owner), U256::zero());
+
+        transact(signed_permit(chain_id, VFY, 0));
+
+        assert!(executed_successfully());
+        assert_eq!(allowance(owner, BOB.into()), U256::from(VFY));
+        assert_eq!(nonce(owner), U256::one());
+    });
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+    ext(MAINNET_CHAIN_ID).execute_with(|| {
+        let owner = owner().1;
+        let calldata = signed_permit(MAINNET_CHAIN_ID, VFY, 0);
+        transact(calldata.clone());
+        // Lower the allowance, so that a replay of the first permit would be visible.
+        transact(signed_permit(MAINNET_CHAIN_ID, VFY / 2, 1));
+        System::reset_events();
+
+        transact(calldata);
+
+        assert!(!executed_successfully());
+        assert_eq!(allowance(owner, BOB.into()), U256::from(VFY / 2));
+        assert_eq!(nonce(owner), U256::from(2));
+    });
+}
+
+#[rstest]
+#[case::mainnet_permit_on_volta(MAINNET_CHAIN_ID, VOLTA_CHAIN_ID)]
+#[case::volta_permit_on_mainnet(VOLTA_CHAIN_ID, MAINNET_CHAIN_ID)]
+fn permit_cannot_be_replayed_across_chains(#[case] signed_for: u64, #[case] chain_id: u64) {
+    ext(chain_id).execute_with(|| {
+        let owner = owner().1;
+
+        transact(signed_permit(signed_for, VFY, 0));
+
+        assert!(!executed_successfully());
+        assert_eq!(allowance(owner, BOB.into()), U256::zero());
+        assert_eq!(nonce(owner), U256::zero());
+    });
+}
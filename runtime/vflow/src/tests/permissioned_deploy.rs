@@ -1,17 +1,19 @@
 use super::*;
 use crate::{
     constants::currency::{CENTS, MICROCENTS},
-    tests::{ExtBuilder, ALICE, BOB},
-    RuntimeOrigin,
+    tests::{
+        eth_address, signed_eth_transaction, signed_set_code_transaction, ExtBuilder, ALICE, BOB,
+    },
+    Balances, RuntimeEvent, RuntimeOrigin,
 };
+use ethereum::{AuthorizationListItem, MalleableTransactionSignature};
 use frame_support::{assert_err_ignore_postinfo, assert_ok};
-use sp_core::H256;
-use sp_runtime::DispatchError;
+use sp_core::{ecdsa, Pair, H160, H256, U256};
 
 #[test]
 fn create_with_whitelisted_account_succeeds() {
     ExtBuilder::default()
-        .with_balances(vec![(ALICE.into(), 11 * CENTS)])
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
         .build()
         .execute_with(|| {
             pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
@@ -38,7 +40,7 @@ fn create_with_whitelisted_account_succeeds() {
 #[test]
 fn create2_with_whitelisted_account_succeeds() {
     ExtBuilder::default()
-        .with_balances(vec![(ALICE.into(), 11 * CENTS)])
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
         .build()
         .execute_with(|| {
             pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
@@ -88,7 +90,7 @@ fn create_with_non_whitelisted_account_fails() {
                     Vec::new(),
                     Vec::new(),
                 ),
-                DispatchError::Other("Not allowed to deploy")
+                pallet_deployment_permissions::Error::<Runtime>::NotAllowedToDeploy
             );
         });
 }
@@ -119,11 +121,1023 @@ fn create2_with_non_whitelisted_account_fails() {
                     Vec::new(),
                     Vec::new(),
                 ),
-                DispatchError::Other("Not allowed to deploy")
+                pallet_deployment_permissions::Error::<Runtime>::NotAllowedToDeploy
+            );
+        });
+}
+
+#[test]
+fn create_with_expired_permission_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_temporary_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                2,
+            )
+            .unwrap();
+            System::set_block_number(2);
+
+            assert_err_ignore_postinfo!(
+                pallet_evm::Pallet::<Runtime>::create(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    contract_bytecode(),
+                    0.into(),
+                    1_000_000,
+                    MICROCENTS.into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                ),
+                pallet_deployment_permissions::Error::<Runtime>::NotAllowedToDeploy
+            );
+        });
+}
+
+#[test]
+fn create_allowed_init_code_with_non_whitelisted_account_succeeds() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::allow_init_code_hash(
+                RuntimeOrigin::root(),
+                H256(sp_io::hashing::keccak_256(&contract_bytecode())),
+            )
+            .unwrap();
+
+            assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                100_000,
+                (100 * MICROCENTS).into(),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+            ));
+        });
+}
+
+#[test]
+fn create_records_deployed_contract() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            System::set_block_number(1);
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+
+            assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                100_000,
+                (100 * MICROCENTS).into(),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+            ));
+
+            let contract = last_created_contract();
+            let init_code_hash = H256(sp_io::hashing::keccak_256(&contract_bytecode()));
+            assert_eq!(
+                pallet_deployment_permissions::DeployedContracts::<Runtime>::get(contract),
+                Some(pallet_deployment_permissions::DeployedContract {
+                    deployer: ALICE.into(),
+                    init_code_hash,
+                    deployed_at: 1,
+                })
+            );
+            System::assert_has_event(
+                pallet_deployment_permissions::Event::<Runtime>::ContractDeployed {
+                    contract,
+                    deployer: ALICE.into(),
+                    init_code_hash,
+                }
+                .into(),
+            );
+        });
+}
+
+#[test]
+fn create_holds_deployment_bond_until_contract_is_retired() {
+    use crate::configs::evm::ContractDeploymentBond;
+    use pallet_deployment_permissions_runtime_api::runtime_decl_for_deployment_permissions_api::DeploymentPermissionsApiV1;
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+
+            assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                100_000,
+                (100 * MICROCENTS).into(),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+            ));
+            let contract = last_created_contract();
+
+            assert_eq!(
+                Runtime::deployment_bonds(ALICE.into()),
+                ContractDeploymentBond::get()
+            );
+            assert_eq!(
+                Balances::reserved_balance(AccountId::from(ALICE)),
+                ContractDeploymentBond::get()
+            );
+            // The contract still has code, so only governance can release the bond.
+            assert_err_ignore_postinfo!(
+                pallet_deployment_permissions::Pallet::<Runtime>::release_deployment_bond(
+                    RuntimeOrigin::signed(BOB.into()),
+                    contract,
+                ),
+                pallet_deployment_permissions::Error::<Runtime>::ContractHasCode
+            );
+
+            pallet_deployment_permissions::Pallet::<Runtime>::retire_contract(
+                RuntimeOrigin::root(),
+                contract,
+            )
+            .unwrap();
+
+            assert_eq!(Runtime::deployment_bonds(ALICE.into()), 0);
+            assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+        });
+}
+
+#[test]
+fn create_without_funds_for_deployment_bond_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 11 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+
+            assert_err_ignore_postinfo!(
+                pallet_evm::Pallet::<Runtime>::create(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    contract_bytecode(),
+                    0.into(),
+                    100_000,
+                    (100 * MICROCENTS).into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                ),
+                pallet_deployment_permissions::Error::<Runtime>::CannotAffordDeploymentBond
+            );
+            assert_eq!(System::account_nonce(AccountId::from(ALICE)), 0);
+            assert!(
+                pallet_deployment_permissions::DeployedContracts::<Runtime>::iter()
+                    .next()
+                    .is_none()
+            );
+        });
+}
+
+#[test]
+fn create_beyond_deploy_quota_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+            pallet_deployment_permissions::Pallet::<Runtime>::set_deploy_quota(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                pallet_deployment_permissions::DeployQuota {
+                    max_total: Some(1),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let create = || {
+                pallet_evm::Pallet::<Runtime>::create(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    contract_bytecode(),
+                    0.into(),
+                    100_000,
+                    (100 * MICROCENTS).into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                )
+            };
+            assert_ok!(create());
+            assert_err_ignore_postinfo!(
+                create(),
+                pallet_deployment_permissions::Error::<Runtime>::TotalDeployQuotaExceeded
+            );
+        });
+}
+
+#[test]
+fn create_in_open_mode_with_non_whitelisted_account_succeeds() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::set_deployment_mode(
+                RuntimeOrigin::root(),
+                pallet_deployment_permissions::DeploymentMode::Open,
+            )
+            .unwrap();
+
+            assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                100_000,
+                (100 * MICROCENTS).into(),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+            ));
+        });
+}
+
+#[test]
+fn create_in_frozen_mode_with_whitelisted_account_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+            pallet_deployment_permissions::Pallet::<Runtime>::set_deployment_mode(
+                RuntimeOrigin::root(),
+                pallet_deployment_permissions::DeploymentMode::Frozen,
+            )
+            .unwrap();
+
+            assert_err_ignore_postinfo!(
+                pallet_evm::Pallet::<Runtime>::create(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    contract_bytecode(),
+                    0.into(),
+                    1_000_000,
+                    MICROCENTS.into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                ),
+                pallet_deployment_permissions::Error::<Runtime>::DeploymentsFrozen
             );
         });
 }
 
+#[test]
+fn create_runtime_api_reports_rejected_deployment() {
+    use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+    use pallet_evm::{ExitError, ExitReason};
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let info = Runtime::create(
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                1_000_000.into(),
+                None,
+                None,
+                None,
+                true,
+                None,
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(
+                info.exit_reason,
+                ExitReason::Error(ExitError::Other(
+                    "deployment rejected: NotAllowedToDeploy".into()
+                ))
+            );
+        });
+}
+
+#[test]
+fn runner_validation_rejects_create_without_permission() {
+    use pallet_evm::Runner;
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let err = <Runtime as pallet_evm::Config>::Runner::validate(
+                ALICE.into(),
+                None,
+                contract_bytecode(),
+                0.into(),
+                1_000_000,
+                Some((100 * MICROCENTS).into()),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+                true,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                sp_runtime::DispatchError::from(err.error),
+                pallet_deployment_permissions::Error::<Runtime>::NotAllowedToDeploy.into()
+            );
+        });
+}
+
+#[test]
+fn ethereum_create_without_permission_is_rejected_by_the_pool() {
+    use crate::{configs::evm::TransactionConverter, types::Executive};
+    use fp_rpc::ConvertTransaction;
+    use pallet_deployment_permissions::runner::REJECTED_TRANSACTION_CODE;
+    use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+
+    let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+    let deployer = eth_address(&pair);
+    ExtBuilder::default()
+        .with_balances(vec![(deployer.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let validate = || {
+                Executive::validate_transaction(
+                    TransactionSource::External,
+                    TransactionConverter.convert_transaction(signed_eth_transaction(
+                        &pair,
+                        ethereum::TransactionAction::Create,
+                        contract_bytecode(),
+                    )),
+                    System::parent_hash(),
+                )
+            };
+
+            assert_eq!(
+                validate(),
+                Err(InvalidTransaction::Custom(REJECTED_TRANSACTION_CODE).into())
+            );
+
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                deployer,
+            )
+            .unwrap();
+            assert_ok!(validate());
+        });
+}
+
+#[test]
+fn create_violating_bytecode_policy_fails() {
+    use pallet_deployment_permissions::BytecodePolicy;
+
+    // PUSH2 0x33ff PUSH1 0x00 MSTORE PUSH1 0x02 PUSH1 0x1e RETURN: deploys CALLER SELFDESTRUCT
+    let selfdestruct_deployer = hex::decode("6133ff6000526002601ef3").unwrap();
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            // Events are not recorded on the genesis block.
+            System::set_block_number(1);
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+            pallet_deployment_permissions::Pallet::<Runtime>::set_bytecode_policy(
+                RuntimeOrigin::root(),
+                BytecodePolicy {
+                    max_init_code_len: Some(contract_bytecode().len() as u32 - 1),
+                    max_runtime_code_len: None,
+                    disallowed_opcodes: vec![0xff].try_into().unwrap(),
+                },
+            )
+            .unwrap();
+
+            let create = |init: Vec<u8>| {
+                pallet_evm::Pallet::<Runtime>::create(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    init,
+                    0.into(),
+                    100_000,
+                    (100 * MICROCENTS).into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                )
+            };
+            assert_err_ignore_postinfo!(
+                create(contract_bytecode()),
+                pallet_deployment_permissions::Error::<Runtime>::InitCodeTooLarge
+            );
+            let balance = Balances::free_balance(AccountId::from(ALICE));
+
+            // The runtime code is only known after execution: the deployment is reverted, but
+            // still charged.
+            assert_ok!(create(selfdestruct_deployer));
+            assert!(System::events().into_iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::EVM(pallet_evm::Event::CreatedFailed { .. })
+            )));
+            assert_eq!(System::account_nonce(AccountId::from(ALICE)), 1);
+            assert!(Balances::free_balance(AccountId::from(ALICE)) < balance);
+            assert!(
+                pallet_deployment_permissions::DeployedContracts::<Runtime>::iter()
+                    .next()
+                    .is_none()
+            );
+        });
+}
+
+#[test]
+fn runtime_api_reports_deployment_permissions() {
+    use pallet_deployment_permissions::{DeployQuota, DeploymentMode};
+    use pallet_deployment_permissions_runtime_api::runtime_decl_for_deployment_permissions_api::DeploymentPermissionsApiV1;
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+            )
+            .unwrap();
+            let quota = DeployQuota {
+                max_total: Some(1),
+                max_per_window: None,
+                window: 0,
+            };
+            pallet_deployment_permissions::Pallet::<Runtime>::set_deploy_quota(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                quota.clone(),
+            )
+            .unwrap();
+
+            assert!(Runtime::is_deployer(ALICE.into()));
+            assert!(!Runtime::is_deployer(BOB.into()));
+            let deployers = Runtime::deployers(None, 10);
+            assert_eq!(deployers.len(), 1);
+            assert_eq!(deployers[0].0, H160::from(ALICE));
+            assert!(Runtime::deployers(Some(ALICE.into()), 10).is_empty());
+            assert_eq!(Runtime::deployment_mode(), DeploymentMode::Permissioned);
+            assert_eq!(Runtime::deploy_quota(ALICE.into()), Some(quota));
+            assert_eq!(Runtime::deploy_quota(BOB.into()), None);
+        });
+}
+
+#[test]
+fn runtime_api_caps_deployers_page() {
+    use pallet_deployment_permissions::DeployerInfo;
+    use pallet_deployment_permissions_runtime_api::{
+        runtime_decl_for_deployment_permissions_api::DeploymentPermissionsApiV1,
+        MAX_DEPLOYERS_PER_PAGE,
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        for i in 0..=MAX_DEPLOYERS_PER_PAGE {
+            pallet_deployment_permissions::Deployers::<Runtime>::insert(
+                H160::from_low_u64_be(i.into()),
+                DeployerInfo::default(),
+            );
+        }
+
+        assert_eq!(
+            Runtime::deployers(None, u32::MAX).len(),
+            MAX_DEPLOYERS_PER_PAGE as usize
+        );
+    });
+}
+
+#[test]
+fn create_after_approved_request_succeeds() {
+    use pallet_deployment_permissions_runtime_api::runtime_decl_for_deployment_permissions_api::DeploymentPermissionsApiV1;
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let deposit = crate::configs::evm::DeployPermissionRequestDeposit::get();
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::request_deploy_permission(
+                    RuntimeOrigin::signed(ALICE.into()),
+                    Some(H256::repeat_byte(1)),
+                )
+            );
+            assert_eq!(
+                Balances::free_balance(AccountId::from(ALICE)),
+                1_000 * CENTS - deposit
+            );
+            let pending = Runtime::pending_requests();
+            assert_eq!(pending.len(), 1);
+            assert_eq!(pending[0].0, H160::from(ALICE));
+
+            assert_ok!(
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_deploy_permission_request(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+            );
+            assert!(Runtime::pending_requests().is_empty());
+            assert_eq!(
+                Balances::free_balance(AccountId::from(ALICE)),
+                1_000 * CENTS
+            );
+
+            assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+                RuntimeOrigin::root(),
+                ALICE.into(),
+                contract_bytecode(),
+                0.into(),
+                100_000,
+                (100 * MICROCENTS).into(),
+                None,
+                None,
+                Vec::new(),
+                Vec::new(),
+            ));
+        });
+}
+
+#[test]
+fn call_to_paused_contract_fails() {
+    use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+    use pallet_evm::{ExitError, ExitReason};
+
+    ExtBuilder::default()
+        .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+        .build()
+        .execute_with(|| {
+            let contract = H160::repeat_byte(42);
+            pallet_deployment_permissions::Pallet::<Runtime>::pause_contract(
+                RuntimeOrigin::root(),
+                contract,
+            )
+            .unwrap();
+
+            let call = || {
+                pallet_evm::Pallet::<Runtime>::call(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                    contract,
+                    Vec::new(),
+                    0.into(),
+                    100_000,
+                    (100 * MICROCENTS).into(),
+                    None,
+                    None,
+                    Vec::new(),
+                    Vec::new(),
+                )
+            };
+            assert_err_ignore_postinfo!(
+                call(),
+                pallet_deployment_permissions::Error::<Runtime>::ContractIsPaused
+            );
+            let info = Runtime::call(
+                ALICE.into(),
+                contract,
+                Vec::new(),
+                0.into(),
+                1_000_000.into(),
+                None,
+                None,
+                None,
+                true,
+                None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(
+                info.exit_reason,
+                ExitReason::Error(ExitError::Other("call rejected: ContractIsPaused".into()))
+            );
+
+            pallet_deployment_permissions::Pallet::<Runtime>::unpause_contract(
+                RuntimeOrigin::root(),
+                contract,
+            )
+            .unwrap();
+            assert_ok!(call());
+        });
+}
+
+mod factory {
+    use super::*;
+
+    fn deploy_factory(deployer: H160) -> H160 {
+        assert_ok!(pallet_evm::Pallet::<Runtime>::create(
+            RuntimeOrigin::root(),
+            deployer,
+            factory_bytecode(),
+            0.into(),
+            1_000_000,
+            (100 * MICROCENTS).into(),
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+        ));
+        last_created_contract()
+    }
+
+    fn call_factory(caller: H160, factory: H160) {
+        assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+            RuntimeOrigin::root(),
+            caller,
+            factory,
+            Vec::new(),
+            0.into(),
+            1_000_000,
+            (100 * MICROCENTS).into(),
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+        ));
+    }
+
+    /// The factory stores the address returned by `CREATE` in slot 0, which is zero on failure.
+    fn created_child(factory: H160) -> H256 {
+        pallet_evm::AccountStorages::<Runtime>::get(factory, H256::zero())
+    }
+
+    #[test]
+    fn non_approved_factory_cannot_create_for_non_deployer() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.into(), 1_000 * CENTS),
+                (BOB.into(), 1_000 * CENTS),
+            ])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+
+                call_factory(BOB.into(), factory);
+
+                assert_eq!(created_child(factory), H256::zero());
+            });
+    }
+
+    #[test]
+    fn non_approved_factory_cannot_create_for_deployer() {
+        ExtBuilder::default()
+            .with_balances(vec![(ALICE.into(), 1_000 * CENTS)])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+
+                call_factory(ALICE.into(), factory);
+
+                assert_eq!(created_child(factory), H256::zero());
+            });
+    }
+
+    #[test]
+    fn approved_factory_can_create() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.into(), 1_000 * CENTS),
+                (BOB.into(), 1_000 * CENTS),
+            ])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_factory(
+                    RuntimeOrigin::root(),
+                    factory,
+                )
+                .unwrap();
+
+                call_factory(BOB.into(), factory);
+
+                assert_ne!(created_child(factory), H256::zero());
+            });
+    }
+
+    #[test]
+    fn approved_factory_creates_are_not_checked_nor_recorded() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.into(), 1_000 * CENTS),
+                (BOB.into(), 1_000 * CENTS),
+            ])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_factory(
+                    RuntimeOrigin::root(),
+                    factory,
+                )
+                .unwrap();
+                pallet_deployment_permissions::Pallet::<Runtime>::set_deploy_quota(
+                    RuntimeOrigin::root(),
+                    factory,
+                    pallet_deployment_permissions::DeployQuota {
+                        max_total: Some(0),
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+                call_factory(BOB.into(), factory);
+
+                // Inner creates only check the create origin: the quota of the factory does not
+                // apply, and neither the child nor a bond for it are recorded.
+                let child = H160::from(created_child(factory));
+                assert_ne!(child, H160::zero());
+                assert!(
+                    pallet_deployment_permissions::DeployedContracts::<Runtime>::get(child)
+                        .is_none()
+                );
+                assert!(
+                    pallet_deployment_permissions::ContractBonds::<Runtime>::get(child).is_none()
+                );
+            });
+    }
+
+    #[test]
+    fn approved_factory_cannot_create_in_frozen_mode() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.into(), 1_000 * CENTS),
+                (BOB.into(), 1_000 * CENTS),
+            ])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_factory(
+                    RuntimeOrigin::root(),
+                    factory,
+                )
+                .unwrap();
+                pallet_deployment_permissions::Pallet::<Runtime>::set_deployment_mode(
+                    RuntimeOrigin::root(),
+                    pallet_deployment_permissions::DeploymentMode::Frozen,
+                )
+                .unwrap();
+
+                call_factory(BOB.into(), factory);
+
+                assert_eq!(created_child(factory), H256::zero());
+            });
+    }
+
+    #[test]
+    fn factory_with_deploy_permission_can_create() {
+        ExtBuilder::default()
+            .with_balances(vec![
+                (ALICE.into(), 1_000 * CENTS),
+                (BOB.into(), 1_000 * CENTS),
+            ])
+            .build()
+            .execute_with(|| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    ALICE.into(),
+                )
+                .unwrap();
+                let factory = deploy_factory(ALICE.into());
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    factory,
+                )
+                .unwrap();
+
+                call_factory(BOB.into(), factory);
+
+                assert_ne!(created_child(factory), H256::zero());
+            });
+    }
+
+    /// Signs an EIP-7702 authorization, valid on any chain, delegating the code of the EOA
+    /// owning `pair` to `delegate`. Returns the authorization and the address of the EOA.
+    fn authorize(pair: &ecdsa::Pair, delegate: H160) -> (AuthorizationListItem, H160) {
+        // keccak256(0x05 || rlp([chain_id, address, nonce])), with chain id and nonce set to 0.
+        let mut message = vec![0x05, 0xd7, 0x80, 0x94];
+        message.extend_from_slice(delegate.as_bytes());
+        message.push(0x80);
+        let hash = sp_io::hashing::keccak_256(&message);
+        let signature: [u8; 65] = pair.sign_prehashed(&hash).as_ref().try_into().unwrap();
+        let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &hash).unwrap();
+        let authority = H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..]);
+        let authorization = AuthorizationListItem {
+            chain_id: 0,
+            address: delegate,
+            nonce: U256::zero(),
+            signature: MalleableTransactionSignature {
+                odd_y_parity: signature[64] == 1,
+                r: H256::from_slice(&signature[..32]),
+                s: H256::from_slice(&signature[32..64]),
+            },
+        };
+        (authorization, authority)
+    }
+
+    /// Validates and submits a set-code (type 4) transaction signed by `caller`, installing
+    /// `authorization` and calling its authority, which then runs the delegated code.
+    fn call_delegated(caller: &ecdsa::Pair, authorization: AuthorizationListItem, authority: H160) {
+        use crate::{configs::evm::TransactionConverter, types::Executive};
+        use fp_rpc::ConvertTransaction;
+        use sp_runtime::transaction_validity::TransactionSource;
+
+        let transaction =
+            signed_set_code_transaction(caller, authority, Vec::new(), &[authorization]);
+        assert_ok!(Executive::validate_transaction(
+            TransactionSource::External,
+            TransactionConverter.convert_transaction(transaction.clone()),
+            System::parent_hash(),
+        ));
+        assert_ok!(pallet_ethereum::Pallet::<Runtime>::transact(
+            pallet_ethereum::RawOrigin::EthereumTransaction(eth_address(caller)).into(),
+            transaction,
+        ));
+        assert_eq!(
+            pallet_evm::AccountCodes::<Runtime>::get(authority)[..3],
+            pallet_deployment_permissions::DELEGATION_DESIGNATOR
+        );
+    }
+
+    mod delegated {
+        use super::*;
+
+        /// The signer of the set-code transactions, which never holds a deploy permission.
+        fn caller() -> ecdsa::Pair {
+            ecdsa::Pair::from_seed(&[2u8; 32])
+        }
+
+        fn setup(test: impl FnOnce(H160, AuthorizationListItem, H160)) {
+            let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+            ExtBuilder::default()
+                .with_balances(vec![
+                    (ALICE.into(), 1_000 * CENTS),
+                    (eth_address(&caller()).into(), 1_000 * CENTS),
+                ])
+                .build()
+                .execute_with(|| {
+                    pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                        RuntimeOrigin::root(),
+                        ALICE.into(),
+                    )
+                    .unwrap();
+                    let factory = deploy_factory(ALICE.into());
+                    let (authorization, authority) = authorize(&pair, factory);
+                    test(factory, authorization, authority);
+                });
+        }
+
+        #[test]
+        fn non_deployer_cannot_create() {
+            setup(|_, authorization, authority| {
+                call_delegated(&caller(), authorization, authority);
+
+                assert_eq!(created_child(authority), H256::zero());
+            });
+        }
+
+        #[test]
+        fn deployer_cannot_create_when_invoked_by_others() {
+            setup(|_, authorization, authority| {
+                pallet_deployment_permissions::Pallet::<Runtime>::grant_deploy_permission(
+                    RuntimeOrigin::root(),
+                    authority,
+                )
+                .unwrap();
+
+                call_delegated(&caller(), authorization, authority);
+
+                assert_eq!(created_child(authority), H256::zero());
+            });
+        }
+
+        #[test]
+        fn delegate_approved_as_factory_does_not_allow_create() {
+            setup(|factory, authorization, authority| {
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_factory(
+                    RuntimeOrigin::root(),
+                    factory,
+                )
+                .unwrap();
+
+                call_delegated(&caller(), authorization, authority);
+
+                assert_eq!(created_child(authority), H256::zero());
+            });
+        }
+
+        #[test]
+        fn eoa_approved_as_factory_can_create() {
+            setup(|_, authorization, authority| {
+                pallet_deployment_permissions::Pallet::<Runtime>::approve_factory(
+                    RuntimeOrigin::root(),
+                    authority,
+                )
+                .unwrap();
+
+                call_delegated(&caller(), authorization, authority);
+
+                assert_ne!(created_child(authority), H256::zero());
+            });
+        }
+    }
+}
+
+fn last_created_contract() -> H160 {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Created { address }) => Some(address),
+            _ => None,
+        })
+        .expect("a contract has been created")
+}
+
+fn factory_bytecode() -> Vec<u8> {
+    // Init code copying and returning the runtime code:
+    //     PUSH1 0x0b DUP1 PUSH1 0x0b PUSH1 0x00 CODECOPY PUSH1 0x00 RETURN
+    // Runtime code, deploying an empty contract and storing its address in slot 0:
+    //     PUSH1 0x00 PUSH1 0x00 PUSH1 0x00 CREATE PUSH1 0x00 SSTORE STOP
+    hex::decode(concat!("600b80600b6000396000f3", "600060006000f060005500")).unwrap()
+}
+
 fn contract_bytecode() -> Vec<u8> {
     // pragma solidity >=0.8.2 <0.9.0;
     //
@@ -1,19 +1,24 @@
 use crate::{
-    configs::xcm::{NativeAssetId, RelayLocation},
+    configs::xcm::{NativeAssetId, RelayLocation, TrustedTeleporters},
     constants::currency::VFY,
     tests::ALICE,
-    AccountId, Runtime, RuntimeCall, RuntimeOrigin, ZKVXcm, U256,
+    weights, AccountId, ParachainSystem, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System,
+    ZKVXcm, U256,
 };
-use alloy::primitives::U256 as Uint256;
+use alloy::primitives::{Bytes, FixedBytes, U256 as Uint256};
 use alloy_sol_types::{sol, SolCall, SolValue};
 use cumulus_primitives_core::AbridgedHostConfiguration;
-use fp_evm::CallInfo;
+use fp_evm::{CallInfo, ExitReason};
 use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::{traits::ContainsPair, weights::Weight};
+use pallet_evm::GasWeightMapping;
 use precompile_utils::precompile_set::AddressU64;
-use sp_core::Get;
+use rstest::rstest;
+use sp_core::{Get, H160, H256};
 use sp_runtime::BuildStorage;
-use xcm::v5::{Asset, Assets, Fungibility, Junction, Location, WeightLimit};
-use xcm::{VersionedAssetId, VersionedAssets, VersionedLocation};
+use vflow_runtime_common::xcm_teleport::{WeightInfo, SELECTOR_LOG_TELEPORTED};
+use xcm::v5::{Asset, AssetId, Assets, Fungibility, Instruction, Junction, Location, WeightLimit};
+use xcm::{VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::<Runtime>::default()
@@ -127,6 +132,425 @@ fn xcm_teleport_precompile_delivery_fee_computation_is_correct() {
     });
 }
 
+/// Test that `deliveryFee` charges its benchmarked weight: the ref time as gas on top of the
+/// intrinsic cost of the transaction and the proof size against the PoV limit.
+#[test]
+fn xcm_teleport_precompile_delivery_fee_gas_tracks_weight() {
+    sol! {
+        contract IXcmTeleportPrecompile {
+            function deliveryFee(bytes32 id, uint256 amount) external returns (uint256);
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        let calldata = IXcmTeleportPrecompile::deliveryFeeCall {
+            id: [0x42u8; 32].into(),
+            amount: Uint256::from(VFY),
+        }
+        .abi_encode();
+        let intrinsic_gas = 21_000
+            + calldata
+                .iter()
+                .map(|byte| if *byte == 0 { 4 } else { 16 })
+                .sum::<u64>();
+
+        let CallInfo {
+            used_gas,
+            weight_info,
+            ..
+        } = Runtime::call(
+            ALICE.into(),
+            AddressU64::<2060>::get(),
+            calldata,
+            U256::zero(),
+            U256::from(1_000_000),
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let weight = weights::xcm_teleport_precompile::ZKVEvmWeight::<Runtime>::delivery_fee();
+        let weight_gas = <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight);
+        assert!(used_gas.standard >= U256::from(intrinsic_gas + weight_gas));
+
+        let proof_size_usage = weight_info.unwrap().proof_size_usage.unwrap();
+        assert!(proof_size_usage >= weight.proof_size());
+        let pov_gas =
+            weight.proof_size() * <Runtime as pallet_evm::Config>::GasLimitPovSizeRatio::get();
+        assert!(used_gas.effective >= U256::from(pov_gas));
+    });
+}
+
+/// A sibling zkVerify parachain.
+const SIBLING_PARA_ID: u32 = 2;
+
+sol! {
+    contract IXcmTeleportToParachain {
+        function deliveryFee(uint32 paraId, bytes beneficiary, uint256 amount) external returns (uint256);
+    }
+}
+
+fn call_parachain_delivery_fee(para_id: u32, beneficiary: Vec<u8>, amount: u128) -> CallInfo {
+    let calldata = IXcmTeleportToParachain::deliveryFeeCall {
+        paraId: para_id,
+        beneficiary: Bytes::from(beneficiary),
+        amount: Uint256::from(amount),
+    }
+    .abi_encode();
+
+    Runtime::call(
+        ALICE.into(),
+        AddressU64::<2060>::get(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+/// Test that VFY is only teleported to and from the relay chain and the zkVerify parachains.
+#[test]
+fn vfy_is_teleported_with_zkverify_parachains_only() {
+    new_test_ext().execute_with(|| {
+        let vfy = Asset {
+            id: NativeAssetId::get(),
+            fun: Fungibility::Fungible(VFY),
+        };
+        let parachain = |para_id| Location::new(1, [Junction::Parachain(para_id)]);
+
+        assert!(TrustedTeleporters::contains(&vfy, &RelayLocation::get()));
+        assert!(TrustedTeleporters::contains(
+            &vfy,
+            &parachain(SIBLING_PARA_ID)
+        ));
+        // Other system parachains are not trusted either.
+        assert!(!TrustedTeleporters::contains(&vfy, &parachain(1000)));
+        assert!(!TrustedTeleporters::contains(&vfy, &parachain(2000)));
+        assert!(!TrustedTeleporters::contains(
+            &vfy,
+            &parachain(crate::ParachainInfo::parachain_id().into())
+        ));
+    });
+}
+
+#[rstest]
+#[case::account_id_32(vec![0x42; 32])]
+#[case::account_key_20(vec![0x42; 20])]
+fn xcm_teleport_precompile_parachain_delivery_fee_computation_is_correct(
+    #[case] beneficiary: Vec<u8>,
+) {
+    new_test_ext().execute_with(|| {
+        ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(SIBLING_PARA_ID.into());
+
+        let CallInfo { value, .. } =
+            call_parachain_delivery_fee(SIBLING_PARA_ID, beneficiary.clone(), VFY);
+        let fees_from_precompile: u128 = Uint256::abi_decode(&value).unwrap().to();
+
+        let junction = match beneficiary.len() {
+            32 => Junction::AccountId32 {
+                network: None,
+                id: beneficiary.try_into().unwrap(),
+            },
+            _ => Junction::AccountKey20 {
+                network: None,
+                key: beneficiary.try_into().unwrap(),
+            },
+        };
+        let destination =
+            VersionedLocation::V5(Location::new(1, [Junction::Parachain(SIBLING_PARA_ID)]));
+        let call = pallet_xcm::Call::<Runtime>::teleport_assets {
+            dest: Box::new(destination.clone()),
+            beneficiary: Box::new(VersionedLocation::V5(Location::new(0, [junction]))),
+            assets: Box::new(VersionedAssets::V5(Assets::from(vec![Asset {
+                id: NativeAssetId::get(),
+                fun: Fungibility::Fungible(VFY),
+            }]))),
+            fee_asset_item: 0,
+        }
+        .into();
+        let fees_from_dry_run =
+            compute_delivery_fees_for_call(RuntimeOrigin::signed(ALICE.into()), call, destination);
+
+        assert!(fees_from_precompile > 0);
+        assert_eq!(fees_from_precompile, fees_from_dry_run);
+    });
+}
+
+#[rstest]
+#[case::not_a_zkverify_parachain(2000, vec![0x42; 32])]
+#[case::other_system_parachain(1000, vec![0x42; 32])]
+#[case::beneficiary_too_short(SIBLING_PARA_ID, vec![0x42; 19])]
+#[case::beneficiary_of_unknown_size(SIBLING_PARA_ID, vec![0x42; 24])]
+#[case::beneficiary_too_long(SIBLING_PARA_ID, vec![0x42; 33])]
+fn xcm_teleport_precompile_rejects_invalid_parachain_teleports(
+    #[case] para_id: u32,
+    #[case] beneficiary: Vec<u8>,
+) {
+    new_test_ext().execute_with(|| {
+        ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id.into());
+
+        let CallInfo { exit_reason, .. } = call_parachain_delivery_fee(para_id, beneficiary, VFY);
+
+        assert!(matches!(exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+/// Test that a teleport returns the id of the message it sends and logs it.
+#[test]
+fn xcm_teleport_precompile_returns_and_logs_message_id() {
+    sol! {
+        contract IXcmTeleportPrecompile {
+            function teleportToRelayChain(bytes32 id, uint256 amount) external returns (bytes32);
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        // Events are not recorded on the genesis block.
+        System::set_block_number(1);
+        let beneficiary = [0x42u8; 32];
+        let calldata = IXcmTeleportPrecompile::teleportToRelayChainCall {
+            id: beneficiary.into(),
+            amount: Uint256::from(VFY),
+        }
+        .abi_encode();
+
+        let CallInfo {
+            exit_reason,
+            value,
+            logs,
+            ..
+        } = Runtime::call(
+            ALICE.into(),
+            AddressU64::<2060>::get(),
+            calldata,
+            U256::zero(),
+            U256::from(1_000_000),
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(matches!(exit_reason, ExitReason::Succeed(_)));
+
+        let sent_message_ids = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::ZKVXcm(pallet_xcm::Event::Sent { message_id, .. }) => {
+                    Some(message_id)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sent_message_ids.len(), 1);
+        let message_id = sent_message_ids[0];
+        assert_eq!(value, message_id.to_vec());
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(
+            logs[0].topics,
+            vec![
+                H256(SELECTOR_LOG_TELEPORTED),
+                H160::from(ALICE).into(),
+                H256(beneficiary),
+            ]
+        );
+        assert_eq!(
+            logs[0].data,
+            (Uint256::from(VFY), FixedBytes::<32>::from(message_id)).abi_encode_params()
+        );
+    });
+}
+
+/// Test that `deliveryFee` quotes the message a teleport actually sends.
+#[test]
+fn xcm_teleport_precompile_quotes_the_message_it_sends() {
+    sol! {
+        contract IXcmTeleportPrecompile {
+            function teleportToRelayChain(bytes32 id, uint256 amount) external returns (bytes32);
+            function deliveryFee(bytes32 id, uint256 amount) external returns (uint256);
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        // Events are not recorded on the genesis block.
+        System::set_block_number(1);
+        let beneficiary = [0x42u8; 32];
+
+        let CallInfo { value, .. } = call_teleport_precompile(
+            IXcmTeleportPrecompile::deliveryFeeCall {
+                id: beneficiary.into(),
+                amount: Uint256::from(VFY),
+            }
+            .abi_encode(),
+        );
+        let quoted_fee: u128 = Uint256::abi_decode(&value).unwrap().to();
+
+        let CallInfo { exit_reason, .. } = call_teleport_precompile(
+            IXcmTeleportPrecompile::teleportToRelayChainCall {
+                id: beneficiary.into(),
+                amount: Uint256::from(VFY),
+            }
+            .abi_encode(),
+        );
+        assert!(matches!(exit_reason, ExitReason::Succeed(_)));
+
+        let (message, message_id) = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::ZKVXcm(pallet_xcm::Event::Sent {
+                    message,
+                    message_id,
+                    ..
+                }) => Some((message, message_id)),
+                _ => None,
+            })
+            .expect("the teleport has been sent");
+        assert_eq!(message.0.last(), Some(&Instruction::SetTopic(message_id)));
+
+        let sent_fee: Assets = ZKVXcm::query_delivery_fees::<()>(
+            VersionedLocation::V5(RelayLocation::get()),
+            VersionedXcm::V5(message),
+            VersionedAssetId::from(NativeAssetId::get()),
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert!(quoted_fee > 0);
+        assert_eq!(
+            sent_fee.get(0).unwrap().fun,
+            Fungibility::Fungible(quoted_fee)
+        );
+    });
+}
+
+sol! {
+    contract IXcmTeleportWithLimits {
+        function teleportToRelayChainWithLimits(bytes32 id, uint256 amount, uint256 maxFee, uint64 weightLimit) external returns (bytes32);
+        function deliveryFee(bytes32 id, uint256 amount, uint256 maxFee, uint64 weightLimit) external returns (uint256);
+    }
+}
+
+fn call_teleport_precompile(calldata: Vec<u8>) -> CallInfo {
+    Runtime::call(
+        ALICE.into(),
+        AddressU64::<2060>::get(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+/// Test that a limited teleport pays the relay chain execution with at most `maxFee` for at most
+/// `weightLimit`, and that `deliveryFee` quotes the message it sends.
+#[test]
+fn xcm_teleport_precompile_teleports_with_limits() {
+    new_test_ext().execute_with(|| {
+        // Events are not recorded on the genesis block.
+        System::set_block_number(1);
+        let beneficiary = [0x42u8; 32];
+        let max_fee = VFY / 10;
+        let weight_limit = 1_000_000_000;
+
+        let CallInfo { value, .. } = call_teleport_precompile(
+            IXcmTeleportWithLimits::deliveryFeeCall {
+                id: beneficiary.into(),
+                amount: Uint256::from(VFY),
+                maxFee: Uint256::from(max_fee),
+                weightLimit: weight_limit,
+            }
+            .abi_encode(),
+        );
+        let quoted_fee: u128 = Uint256::abi_decode(&value).unwrap().to();
+
+        let CallInfo {
+            exit_reason, value, ..
+        } = call_teleport_precompile(
+            IXcmTeleportWithLimits::teleportToRelayChainWithLimitsCall {
+                id: beneficiary.into(),
+                amount: Uint256::from(VFY),
+                maxFee: Uint256::from(max_fee),
+                weightLimit: weight_limit,
+            }
+            .abi_encode(),
+        );
+        assert!(matches!(exit_reason, ExitReason::Succeed(_)));
+
+        let (message, message_id) = System::events()
+            .into_iter()
+            .find_map(|record| match record.event {
+                RuntimeEvent::ZKVXcm(pallet_xcm::Event::Sent {
+                    message,
+                    message_id,
+                    ..
+                }) => Some((message, message_id)),
+                _ => None,
+            })
+            .expect("the teleport has been sent");
+        assert_eq!(value, message_id.to_vec());
+        assert!(message.0.contains(&Instruction::BuyExecution {
+            fees: Asset {
+                id: AssetId(Location::here()),
+                fun: Fungibility::Fungible(max_fee),
+            },
+            weight_limit: WeightLimit::Limited(Weight::from_parts(
+                weight_limit,
+                cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64
+            )),
+        }));
+
+        let sent_fee: Assets = ZKVXcm::query_delivery_fees::<()>(
+            VersionedLocation::V5(RelayLocation::get()),
+            VersionedXcm::V5(message),
+            VersionedAssetId::from(NativeAssetId::get()),
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert!(quoted_fee > 0);
+        assert_eq!(
+            sent_fee.get(0).unwrap().fun,
+            Fungibility::Fungible(quoted_fee)
+        );
+    });
+}
+
+#[test]
+fn xcm_teleport_precompile_rejects_max_fee_above_amount() {
+    new_test_ext().execute_with(|| {
+        let CallInfo { exit_reason, .. } = call_teleport_precompile(
+            IXcmTeleportWithLimits::teleportToRelayChainWithLimitsCall {
+                id: [0x42u8; 32].into(),
+                amount: Uint256::from(VFY),
+                maxFee: Uint256::from(VFY + 1),
+                weightLimit: 1_000_000_000,
+            }
+            .abi_encode(),
+        );
+
+        assert!(matches!(exit_reason, ExitReason::Revert(_)));
+    });
+}
+
 fn compute_teleport_delivery_fees_via_precompile(
     from: [u8; 20],
     account: [u8; 32],
@@ -0,0 +1,215 @@
+use crate::{
+    configs::xcm::{NativeAssetId, RelayLocation},
+    constants::currency::{MICROCENTS, VFY},
+    tests::{ALICE, BOB},
+    AccountId, Balances, Runtime, RuntimeEvent, RuntimeOrigin, System, ZKVXcm, U256,
+};
+use alloy::primitives::{Bytes, U256 as Uint256};
+use alloy_sol_types::{sol, SolCall, SolValue};
+use fp_evm::{CallInfo, ExitReason};
+use fp_rpc::runtime_decl_for_ethereum_runtime_rpc_api::EthereumRuntimeRPCApiV6;
+use frame_support::{
+    assert_ok,
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFee},
+};
+use parity_scale_codec::Encode;
+use precompile_utils::precompile_set::AddressU64;
+use sp_core::{Get, H160};
+use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
+
+sol! {
+    contract IXcmUtils {
+        function xcmSend(bytes dest, bytes message) external;
+        function xcmExecute(bytes message, uint64 maxWeight) external;
+        function weighMessage(bytes message) external view returns (uint64);
+        function getUnitsPerSecond(bytes assetLocation) external view returns (uint256);
+    }
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = super::xcm_teleport_integration::new_test_ext();
+    // Events are not recorded on the genesis block.
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn precompile_address() -> H160 {
+    AddressU64::<2062>::get()
+}
+
+fn view(calldata: Vec<u8>) -> CallInfo {
+    Runtime::call(
+        ALICE.into(),
+        precompile_address(),
+        calldata,
+        U256::zero(),
+        U256::from(1_000_000),
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+fn transact(calldata: Vec<u8>) {
+    assert_ok!(pallet_evm::Pallet::<Runtime>::call(
+        RuntimeOrigin::root(),
+        ALICE.into(),
+        precompile_address(),
+        calldata,
+        0.into(),
+        1_000_000,
+        (100 * MICROCENTS).into(),
+        None,
+        None,
+        Vec::new(),
+        Vec::new(),
+    ));
+}
+
+fn executed_successfully() -> bool {
+    System::events().into_iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::EVM(pallet_evm::Event::Executed { .. })
+        )
+    })
+}
+
+fn encoded_location(location: Location) -> Bytes {
+    VersionedLocation::from(location).encode().into()
+}
+
+fn encoded_message(message: Xcm<()>) -> Bytes {
+    VersionedXcm::from(message).encode().into()
+}
+
+/// Withdraws `amount` of VFY from the origin and deposits it to BOB.
+fn transfer_to_bob(amount: u128) -> Xcm<()> {
+    Xcm(vec![
+        WithdrawAsset((NativeAssetId::get(), amount).into()),
+        DepositAsset {
+            assets: Wild(AllCounted(1)),
+            beneficiary: AccountKey20 {
+                network: None,
+                key: BOB,
+            }
+            .into(),
+        },
+    ])
+}
+
+#[test]
+fn weigh_message_matches_pallet_xcm() {
+    new_test_ext().execute_with(|| {
+        let message = transfer_to_bob(VFY);
+        let expected = ZKVXcm::query_xcm_weight(VersionedXcm::from(message.clone()))
+            .unwrap()
+            .ref_time();
+
+        let calldata = IXcmUtils::weighMessageCall {
+            message: encoded_message(message),
+        }
+        .abi_encode();
+        let weight = u64::abi_decode(&view(calldata).value).unwrap();
+
+        assert_eq!(weight, expected);
+    });
+}
+
+#[test]
+fn units_per_second_is_only_quoted_in_native_asset() {
+    new_test_ext().execute_with(|| {
+        let units_per_second = |location: Location| {
+            let calldata = IXcmUtils::getUnitsPerSecondCall {
+                assetLocation: encoded_location(location),
+            }
+            .abi_encode();
+            view(calldata)
+        };
+
+        let CallInfo {
+            exit_reason, value, ..
+        } = units_per_second(NativeAssetId::get().0);
+        assert!(matches!(exit_reason, ExitReason::Succeed(_)));
+        let expected = <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+            &Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0),
+        );
+        assert_eq!(
+            Uint256::abi_decode(&value).unwrap(),
+            Uint256::from(expected)
+        );
+
+        let CallInfo { exit_reason, .. } = units_per_second(Location::here());
+        assert!(matches!(exit_reason, ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn xcm_execute_runs_message_as_caller() {
+    new_test_ext().execute_with(|| {
+        let amount = VFY;
+        let calldata = IXcmUtils::xcmExecuteCall {
+            message: encoded_message(transfer_to_bob(amount)),
+            maxWeight: 10_000_000_000,
+        }
+        .abi_encode();
+
+        transact(calldata);
+
+        assert!(executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), amount);
+    });
+}
+
+#[test]
+fn xcm_execute_fails_if_max_weight_is_too_low() {
+    new_test_ext().execute_with(|| {
+        let calldata = IXcmUtils::xcmExecuteCall {
+            message: encoded_message(transfer_to_bob(VFY)),
+            maxWeight: 1,
+        }
+        .abi_encode();
+
+        transact(calldata);
+
+        assert!(!executed_successfully());
+        assert_eq!(Balances::free_balance(AccountId::from(BOB)), 0);
+    });
+}
+
+#[test]
+fn xcm_send_delivers_message_to_relay_chain() {
+    new_test_ext().execute_with(|| {
+        let calldata = IXcmUtils::xcmSendCall {
+            dest: encoded_location(RelayLocation::get()),
+            message: encoded_message(Xcm(vec![ClearOrigin])),
+        }
+        .abi_encode();
+
+        transact(calldata);
+
+        assert!(executed_successfully());
+        assert!(System::events().into_iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::ZKVXcm(pallet_xcm::Event::Sent { .. })
+        )));
+    });
+}
+
+#[test]
+fn undecodable_message_is_reverted() {
+    new_test_ext().execute_with(|| {
+        let calldata = IXcmUtils::weighMessageCall {
+            message: vec![0xff; 4].into(),
+        }
+        .abi_encode();
+
+        let CallInfo { exit_reason, .. } = view(calldata);
+
+        assert!(matches!(exit_reason, ExitReason::Revert(_)));
+    });
+}
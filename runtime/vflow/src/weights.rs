@@ -17,6 +17,7 @@
 //! Expose the auto generated weight files.
 
 pub mod block_weights;
+pub mod call_permit_precompile;
 pub mod cumulus_pallet_parachain_system;
 pub mod cumulus_pallet_xcmp_queue;
 pub mod db;
@@ -36,4 +37,6 @@ pub mod pallet_transaction_payment;
 pub mod pallet_utility;
 pub mod pallet_xcm;
 pub mod pallet_xcm_benchmarks;
+pub mod xcm_teleport_precompile;
+pub mod xcm_utils_precompile;
 pub use extrinsic_weights::ExtrinsicBaseWeight;
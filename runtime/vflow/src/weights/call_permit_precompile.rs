@@ -0,0 +1,56 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `call_permit_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet call_permit_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use crate::weights_aliases::*;
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `call_permit_precompile` using the zkVerify node and recommended hardware.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> vflow_runtime_common::call_permit::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `Timestamp::Now` (r:1 w:0)
+    /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `PrecompileCallPermit::Nonces` (r:1 w:1)
+    /// Proof: `PrecompileCallPermit::Nonces` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `EVMChainId::ChainId` (r:1 w:0)
+    /// Proof: `EVMChainId::ChainId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 65536]`.
+    fn dispatch(n: u32, ) -> Weight {
+        Weight::from_parts(69_480_000, 3471)
+            .saturating_add(Weight::from_parts(1_692, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EVMChainId::ChainId` (r:1 w:0)
+    /// Proof: `EVMChainId::ChainId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    fn domain_separator() -> Weight {
+        Weight::from_parts(6_037_000, 1493)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+}
@@ -13,37 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_deployment_permissions`
+//! Weights for `pallet_deployment_permissions`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 53.0.0
-//! DATE: 2026-03-10, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `71e0398e364b`, CPU: `AMD Ryzen 7 7700 8-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// /usr/local/bin/vflow-node
-// benchmark
-// pallet
-// --runtime
-// /app/vflow_runtime.compact.compressed.wasm
-// --genesis-builder=runtime
-// --pallet
-// pallet-deployment-permissions
-// --extrinsic
-// *
-// --steps
-// 50
-// --repeat
-// 20
-// --heap-pages=4096
-// --header
-// /data/benchmark/HEADER-APACHE2
-// --output
-// /data/benchmark/runtime/vflow/src/weights/pallet_deployment_permissions.rs
-// --template
-// /data/benchmark/scripts/templates/deploy-weight-template.hbs
-// --base-path=/tmp/tmp.ULYpBzd15e
+//! PROVISIONAL ESTIMATES: these values are not the output of a benchmark run of the current
+//! code. The weights of `grant_deploy_permission` and `revoke_deploy_permission`, generated by
+//! the benchmark CLI (version 53.0.0, on 2026-03-10), have since been adjusted by hand, and the
+//! weights of the other calls have been written by hand.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet pallet-deployment-permissions`, before release.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -58,26 +36,276 @@ use core::marker::PhantomData;
 pub struct ZKVEvmWeight<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> pallet_deployment_permissions::WeightInfo for ZKVEvmWeight<T> {
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
     /// Storage: `DeploymentPermissions::Deployers` (r:1 w:1)
-    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
     fn grant_deploy_permission() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `300`
-        //  Estimated: `3501`
-        // Minimum execution time: 14_136_000 picoseconds.
-        Weight::from_parts(14_798_000, 3501)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
+        Weight::from_parts(14_798_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
     /// Storage: `DeploymentPermissions::Deployers` (r:1 w:1)
-    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
     fn revoke_deploy_permission() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `341`
-        //  Estimated: `3501`
-        // Minimum execution time: 15_559_000 picoseconds.
-        Weight::from_parts(16_190_000, 3501)
+        Weight::from_parts(16_190_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `System::Number` (r:1 w:0)
+    /// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn grant_temporary_deploy_permission() -> Weight {
+        Weight::from_parts(15_640_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::PruneCursor` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PruneCursor` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:101 w:100)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 100]`.
+    fn prune_expired_deployers(n: u32, ) -> Weight {
+        Weight::from_parts(7_890_000, 3597)
+            .saturating_add(Weight::from_parts(11_372_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2607).saturating_mul(n.into()))
+    }
+    /// Storage: `DeploymentPermissions::AllowedInitCodeHashes` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::AllowedInitCodeHashes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn allow_init_code_hash() -> Weight {
+        Weight::from_parts(15_023_000, 3497)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::AllowedInitCodeHashes` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::AllowedInitCodeHashes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn disallow_init_code_hash() -> Weight {
+        Weight::from_parts(16_455_000, 3497)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::ApprovedFactories` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::ApprovedFactories` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn approve_factory() -> Weight {
+        Weight::from_parts(14_901_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::ApprovedFactories` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::ApprovedFactories` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn revoke_factory() -> Weight {
+        Weight::from_parts(16_247_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn add_manager() -> Weight {
+        Weight::from_parts(14_603_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn remove_manager() -> Weight {
+        Weight::from_parts(16_011_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployQuotas` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::DeployQuotas` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployCounters` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::DeployCounters` (`max_values`: None, `max_size`: Some(441), added: 2916, mode: `MaxEncodedLen`)
+    fn set_deploy_quota() -> Weight {
+        Weight::from_parts(18_907_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployQuotas` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::DeployQuotas` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployCounters` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::DeployCounters` (`max_values`: None, `max_size`: Some(441), added: 2916, mode: `MaxEncodedLen`)
+    fn remove_deploy_quota() -> Weight {
+        Weight::from_parts(21_376_000, 3515)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `DeploymentPermissions::Mode` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::Mode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    fn set_deployment_mode() -> Weight {
+        Weight::from_parts(8_394_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Deployers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::PendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PendingRequests` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::CounterForPendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::CounterForPendingRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn request_deploy_permission() -> Weight {
+        Weight::from_parts(63_927_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::PendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PendingRequests` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::CounterForPendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::CounterForPendingRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn approve_deploy_permission_request() -> Weight {
+        Weight::from_parts(74_065_500, 3597)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::PendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PendingRequests` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::CounterForPendingRequests` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::CounterForPendingRequests` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::TotalIssuance` (r:1 w:1)
+    /// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    fn reject_deploy_permission_request() -> Weight {
+        Weight::from_parts(77_793_000, 3581)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: `DeploymentPermissions::CodePolicy` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::CodePolicy` (`max_values`: Some(1), `max_size`: Some(268), added: 763, mode: `MaxEncodedLen`)
+    fn set_bytecode_policy() -> Weight {
+        Weight::from_parts(11_087_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::PausedContracts` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PausedContracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn pause_contract() -> Weight {
+        Weight::from_parts(14_805_000, 3501)
             .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+    /// Storage: `DeploymentPermissions::PausedContracts` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::PausedContracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn unpause_contract() -> Weight {
+        Weight::from_parts(16_993_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn set_deployer_metadata() -> Weight {
+        Weight::from_parts(18_582_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:100 w:100)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn grant_deploy_permissions(n: u32, ) -> Weight {
+        Weight::from_parts(10_318_000, 3501)
+            .saturating_add(Weight::from_parts(8_127_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2607).saturating_mul(n.into()))
+    }
+    /// Storage: `DeploymentPermissions::Managers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Managers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:100 w:100)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn revoke_deploy_permissions(n: u32, ) -> Weight {
+        Weight::from_parts(9_611_000, 3501)
+            .saturating_add(Weight::from_parts(7_493_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2607).saturating_mul(n.into()))
+    }
+    /// Storage: `DeploymentPermissions::ContractBonds` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::ContractBonds` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn retire_contract() -> Weight {
+        Weight::from_parts(56_220_000, 3581)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `EVM::AccountCodes` (r:1 w:0)
+    /// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `DeploymentPermissions::ContractBonds` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::ContractBonds` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+    fn release_deployment_bond() -> Weight {
+        Weight::from_parts(57_736_500, 3581)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `DeploymentPermissions::Mode` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Mode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+    /// Storage: `System::Number` (r:1 w:0)
+    /// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::Deployers` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::Deployers` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::AllowedInitCodeHashes` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::AllowedInitCodeHashes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::CodePolicy` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::CodePolicy` (`max_values`: Some(1), `max_size`: Some(268), added: 763, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployQuotas` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::DeployQuotas` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployCounters` (r:1 w:1)
+    /// Proof: `DeploymentPermissions::DeployCounters` (`max_values`: None, `max_size`: Some(441), added: 2916, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::ContractBonds` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::ContractBonds` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `DeploymentPermissions::DeployedContracts` (r:0 w:1)
+    /// Proof: `DeploymentPermissions::DeployedContracts` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    fn check_create() -> Weight {
+        Weight::from_parts(86_000_000, 14897)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `DeploymentPermissions::PausedContracts` (r:1 w:0)
+    /// Proof: `DeploymentPermissions::PausedContracts` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn check_call() -> Weight {
+        Weight::from_parts(4_300_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
 }
@@ -0,0 +1,71 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `xcm_teleport_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet xcm_teleport_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use crate::weights_aliases::*;
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `xcm_teleport_precompile` using the zkVerify node and recommended hardware.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> vflow_runtime_common::xcm_teleport::WeightInfo for ZKVEvmWeight<T> {
+    fn teleport_to_relay_chain() -> Weight {
+        Weight::from_parts(4_612_000, 0)
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn teleport_to_parachain() -> Weight {
+        Weight::from_parts(8_077_000, 1489)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+    fn teleport_to_relay_chain_with_limits() -> Weight {
+        Weight::from_parts(10_472_000, 0)
+    }
+    /// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+    /// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `ZKVXcm::SupportedVersion` (r:1 w:0)
+    /// Proof: `ZKVXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+    /// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn delivery_fee() -> Weight {
+        Weight::from_parts(21_634_000, 3540)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+    }
+    /// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+    /// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+    /// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `ZKVXcm::SupportedVersion` (r:1 w:0)
+    /// Proof: `ZKVXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    /// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+    /// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn delivery_fee_to_parachain() -> Weight {
+        Weight::from_parts(28_562_000, 3603)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+    }
+}
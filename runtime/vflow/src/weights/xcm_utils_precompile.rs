@@ -0,0 +1,50 @@
+// Copyright 2024, Horizen Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `xcm_utils_precompile`.
+//!
+//! PROVISIONAL ESTIMATES: these values have been written by hand, they are not the output of
+//! a benchmark run.
+//!
+//! TODO: regenerate this file on the reference hardware, with
+//! `benchmark pallet --pallet xcm_utils_precompile`, before release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use crate::weights_aliases::*;
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `xcm_utils_precompile` using the zkVerify node and recommended hardware.
+pub struct ZKVEvmWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> vflow_runtime_common::xcm_utils::WeightInfo for ZKVEvmWeight<T> {
+    /// The range of component `n` is `[0, 65436]`.
+    fn decode_message(n: u32, ) -> Weight {
+        Weight::from_parts(2_104_000, 0)
+            .saturating_add(Weight::from_parts(229, 0).saturating_mul(n.into()))
+    }
+    /// The range of component `n` is `[0, 65436]`.
+    fn weigh_message(n: u32, ) -> Weight {
+        Weight::from_parts(5_840_000, 0)
+            .saturating_add(Weight::from_parts(693, 0).saturating_mul(n.into()))
+    }
+    fn get_units_per_second() -> Weight {
+        Weight::from_parts(1_662_000, 0)
+    }
+}